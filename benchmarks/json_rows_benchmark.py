@@ -0,0 +1,67 @@
+#!/usr/bin/env python3
+"""Benchmark for encoding lists of homogeneous dicts (e.g. query result rows).
+
+Compares the Rust ``encode_rows`` helper, with and without a schema hint,
+against the standard library ``json.dumps``.
+"""
+
+import json
+import statistics
+import time
+from collections.abc import Callable
+
+from velithon._velithon import encode_rows
+
+ROW_COUNT = 10_000
+FIELDS = ['id', 'name', 'email', 'score', 'active']
+
+
+def make_rows(count: int) -> list[dict]:
+    """Build ``count`` rows with the same five fields."""
+    return [
+        {
+            'id': i,
+            'name': f'user-{i}',
+            'email': f'user-{i}@example.com',
+            'score': i * 0.5,
+            'active': i % 2 == 0,
+        }
+        for i in range(count)
+    ]
+
+
+def benchmark(name: str, encode: Callable[[], bytes], rounds: int = 20) -> float:
+    """Return the median time in milliseconds for one encode call."""
+    for _ in range(3):
+        encode()
+
+    times = []
+    for _ in range(rounds):
+        start = time.perf_counter()
+        encode()
+        times.append((time.perf_counter() - start) * 1000)
+
+    median = statistics.median(times)
+    print(f'  {name:<28} {median:8.2f} ms')
+    return median
+
+
+def main():
+    """Run the row encoding benchmark."""
+    rows = make_rows(ROW_COUNT)
+    assert json.loads(encode_rows(rows)) == rows
+
+    print(f'\n🧪 Encoding {ROW_COUNT:,} rows of {len(FIELDS)} fields...')
+    baseline = benchmark('json.dumps', lambda: json.dumps(rows).encode())
+    inferred = benchmark('encode_rows', lambda: encode_rows(rows))
+    hinted = benchmark(
+        'encode_rows(schema_hint=...)', lambda: encode_rows(rows, schema_hint=FIELDS)
+    )
+
+    print('\n📊 Speedup vs json.dumps:')
+    print(f'  encode_rows:              {baseline / inferred:.2f}x')
+    print(f'  encode_rows with hint:    {baseline / hinted:.2f}x')
+
+
+if __name__ == '__main__':
+    main()
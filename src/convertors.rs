@@ -4,6 +4,12 @@ use regex::Regex;
 use std::collections::HashSet;
 use uuid::Uuid;
 
+const STRING_REGEX: &str = "[^/]+";
+const PATH_REGEX: &str = ".*";
+const INTEGER_REGEX: &str = "[0-9]+";
+const FLOAT_REGEX: &str = "[0-9]+(\\.[0-9]+)?";
+const UUID_REGEX: &str = "[0-9a-fA-F]{8}-?[0-9a-fA-F]{4}-?[0-9a-fA-F]{4}-?[0-9a-fA-F]{4}-?[0-9a-fA-F]{12}";
+
 /// Base trait for all convertors
 #[pyclass(subclass)]
 #[derive(Clone)]
@@ -31,6 +37,12 @@ impl Convertor {
         // raise not implemented error
         Err(pyo3::exceptions::PyNotImplementedError::new_err("Convertor.to_string() must be implemented in subclasses"))
     }
+
+    /// Built-in type name for this convertor's regex, or "custom"
+    #[getter]
+    fn param_type(&self) -> &'static str {
+        convertor_name_for_regex(&self.regex).unwrap_or("custom")
+    }
 }
 /// Individual convertor classes for Python compatibility
 
@@ -45,7 +57,7 @@ impl StringConvertor {
         (
             StringConvertor {},
             Convertor {
-                regex: "[^/]+".to_string(),
+                regex: STRING_REGEX.to_string(),
             },
         )
     }
@@ -76,7 +88,7 @@ impl PathConvertor {
         (
             PathConvertor {},
             Convertor {
-                regex: ".*".to_string(),
+                regex: PATH_REGEX.to_string(),
             },
         )
     }
@@ -99,7 +111,7 @@ impl IntegerConvertor {
         (
             IntegerConvertor {},
             Convertor {
-                regex: "[0-9]+".to_string(),
+                regex: INTEGER_REGEX.to_string(),
             },
         )
     }
@@ -127,7 +139,7 @@ impl FloatConvertor {
         (
             FloatConvertor {},
             Convertor {
-                regex: "[0-9]+(\\.[0-9]+)?".to_string(),
+                regex: FLOAT_REGEX.to_string(),
             },
         )
     }
@@ -165,7 +177,7 @@ impl UUIDConvertor {
         (
             UUIDConvertor {},
             Convertor {
-                regex: "[0-9a-fA-F]{8}-?[0-9a-fA-F]{4}-?[0-9a-fA-F]{4}-?[0-9a-fA-F]{4}-?[0-9a-fA-F]{12}".to_string(),
+                regex: UUID_REGEX.to_string(),
             },
         )
     }
@@ -185,6 +197,161 @@ impl UUIDConvertor {
     }
 }
 
+/// Convertor for `{name:regex}` parameters with a custom regex constraint
+#[pyclass(extends=Convertor, name = "RegexConvertor")]
+pub struct RegexConvertor {
+    anchored: Regex,
+}
+
+#[pymethods]
+impl RegexConvertor {
+    #[new]
+    fn new(regex: &str) -> PyResult<(Self, Convertor)> {
+        validate_custom_regex(regex)?;
+        let anchored = Regex::new(&format!("^(?:{})$", regex))
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid regex: {}", e)))?;
+        Ok((
+            RegexConvertor { anchored },
+            Convertor {
+                regex: regex.to_string(),
+            },
+        ))
+    }
+
+    fn convert(&self, value: &str) -> PyResult<String> {
+        Ok(value.to_string())
+    }
+
+    fn to_string(&self, value: &str) -> PyResult<String> {
+        if !self.anchored.is_match(value) {
+            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                "Value '{}' does not match pattern '{}'",
+                value,
+                self.anchored.as_str()
+            )));
+        }
+        Ok(value.to_string())
+    }
+}
+
+/// A `{name}` or `{name:spec}` placeholder found in a path template
+pub(crate) struct PathParam<'a> {
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+    pub(crate) name: &'a str,
+    pub(crate) spec: Option<&'a str>,
+}
+
+/// Find path parameters. A spec may contain balanced braces (`{year:\d{4}}`);
+/// text that does not form a valid placeholder is left as a literal.
+pub(crate) fn find_path_params(path: &str) -> Vec<PathParam<'_>> {
+    let bytes = path.as_bytes();
+    let mut params = Vec::new();
+    let mut pos = 0;
+    while let Some(offset) = path[pos..].find('{') {
+        let start = pos + offset;
+        pos = start + 1;
+
+        let name_end = bytes[pos..]
+            .iter()
+            .position(|b| !(b.is_ascii_alphanumeric() || *b == b'_'))
+            .map_or(bytes.len(), |len| pos + len);
+        let name = &path[pos..name_end];
+        if name.is_empty() || name.as_bytes()[0].is_ascii_digit() {
+            continue;
+        }
+
+        match bytes.get(name_end) {
+            Some(b'}') => {
+                params.push(PathParam { start, end: name_end + 1, name, spec: None });
+                pos = name_end + 1;
+            }
+            Some(b':') => {
+                let spec_start = name_end + 1;
+                let mut depth = 0usize;
+                let mut escaped = false;
+                let mut spec_end = None;
+                for (idx, b) in bytes.iter().enumerate().skip(spec_start) {
+                    match b {
+                        _ if escaped => escaped = false,
+                        b'\\' => escaped = true,
+                        b'{' => depth += 1,
+                        b'}' if depth == 0 => {
+                            spec_end = Some(idx);
+                            break;
+                        }
+                        b'}' => depth -= 1,
+                        _ => {}
+                    }
+                }
+                if let Some(spec_end) = spec_end.filter(|&end| end > spec_start) {
+                    params.push(PathParam {
+                        start,
+                        end: spec_end + 1,
+                        name,
+                        spec: Some(&path[spec_start..spec_end]),
+                    });
+                    pos = spec_end + 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    params
+}
+
+/// Whether a parameter spec names a convertor type rather than giving a regex
+fn is_convertor_name(spec: &str) -> bool {
+    let mut bytes = spec.bytes();
+    bytes.next().is_some_and(|b| b.is_ascii_alphabetic() || b == b'_')
+        && bytes.all(|b| b.is_ascii_alphanumeric() || b == b'_')
+}
+
+/// Check that a custom parameter regex compiles and does not define its own named groups
+fn validate_custom_regex(regex: &str) -> PyResult<()> {
+    let compiled = Regex::new(regex).map_err(|e| {
+        pyo3::exceptions::PyValueError::new_err(format!("Invalid regex '{}' in path parameter: {}", regex, e))
+    })?;
+    if compiled.capture_names().flatten().next().is_some() {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Regex '{}' in path parameter must not contain named groups",
+            regex
+        )));
+    }
+    Ok(())
+}
+
+/// Convertor for a placeholder spec: a convertor type name looked up in
+/// `convertor_types` (`str` when omitted), or a custom regex constraint
+pub(crate) fn resolve_convertor<'py>(
+    py: Python<'py>,
+    spec: Option<&str>,
+    convertor_types: &Bound<'py, PyDict>,
+) -> PyResult<Bound<'py, PyAny>> {
+    match spec {
+        // Anything that is not a plain type name is a custom regex constraint
+        Some(spec) if !is_convertor_name(spec) => {
+            Ok(Bound::new(py, PyClassInitializer::from(RegexConvertor::new(spec)?))?.into_any())
+        }
+        spec => {
+            let convertor_name = spec.unwrap_or("str");
+            convertor_types.get_item(convertor_name)?
+                .ok_or_else(|| pyo3::exceptions::PyKeyError::new_err(format!("Unknown convertor type: {}", convertor_name)))
+        }
+    }
+}
+
+/// The built-in convertors keyed by type name, as in `velithon.convertors.CONVERTOR_TYPES`
+pub(crate) fn default_convertor_types(py: Python<'_>) -> PyResult<Bound<'_, PyDict>> {
+    let convertors = PyDict::new(py);
+    convertors.set_item("str", Bound::new(py, PyClassInitializer::from(StringConvertor::new()))?)?;
+    convertors.set_item("path", Bound::new(py, PyClassInitializer::from(PathConvertor::new()))?)?;
+    convertors.set_item("int", Bound::new(py, PyClassInitializer::from(IntegerConvertor::new()))?)?;
+    convertors.set_item("float", Bound::new(py, PyClassInitializer::from(FloatConvertor::new()))?)?;
+    convertors.set_item("uuid", Bound::new(py, PyClassInitializer::from(UUIDConvertor::new()))?)?;
+    Ok(convertors)
+}
+
 /// Fast path compilation that leverages pre-compiled regex patterns
 #[pyfunction]
 fn compile_path(py: Python, path: &str, convertor_types: Bound<PyDict>) -> PyResult<(String, String, Py<PyDict>)> {
@@ -196,23 +363,20 @@ fn compile_path(py: Python, path: &str, convertor_types: Bound<PyDict>) -> PyRes
     let mut idx = 0;
     let param_convertors = PyDict::new(py);
 
-    let param_regex = Regex::new(r"\{([a-zA-Z_][a-zA-Z0-9_]*)(:[a-zA-Z_][a-zA-Z0-9_]*)?\}").unwrap();
-    for caps in param_regex.captures_iter(path) {
-        let param_name = caps.get(1).unwrap().as_str();
-        let convertor_name = caps.get(2).map_or("str", |m| m.as_str().trim_start_matches(':'));
-        let convertor = convertor_types.get_item(convertor_name)?
-            .ok_or_else(|| pyo3::exceptions::PyKeyError::new_err(format!("Unknown convertor type: {}", convertor_name)))?;
+    for param in find_path_params(path) {
+        let param_name = param.name;
+        let convertor = resolve_convertor(py, param.spec, &convertor_types)?;
         let regex = match convertor.extract::<Convertor>() {
             Ok(conv) => conv.regex.clone(),
             Err(_) => return Err(pyo3::exceptions::PyTypeError::new_err("Invalid convertor type")),
         };
-        if idx < caps.get(0).unwrap().start() {
-            path_regex.push_str(&regex::escape(&path[idx..caps.get(0).unwrap().start()]));
+        if idx < param.start {
+            path_regex.push_str(&regex::escape(&path[idx..param.start]));
         }
         path_regex.push_str(&format!("(?P<{}>{})", param_name, regex));
 
-        if idx < caps.get(0).unwrap().start() {
-            path_format.push_str(&path[idx..caps.get(0).unwrap().start()]);
+        if idx < param.start {
+            path_format.push_str(&path[idx..param.start]);
         }
         path_format.push_str(&format!("{{{}}}", param_name));
 
@@ -223,9 +387,7 @@ fn compile_path(py: Python, path: &str, convertor_types: Bound<PyDict>) -> PyRes
         // Store the convertor in the dictionary
         param_convertors.set_item(param_name, convertor)?;
 
-        if let Some(matched) = caps.get(0) {
-            idx = matched.end();
-        }
+        idx = param.end;
     }
     if duplicated_params.len() > 0 {
         return Err(pyo3::exceptions::PyValueError::new_err(format!(
@@ -251,6 +413,108 @@ fn compile_path(py: Python, path: &str, convertor_types: Bound<PyDict>) -> PyRes
     Ok((path_regex, path_format, param_convertors.unbind()))
 }
 
+/// Recover the original path template from a regex produced by `compile_path`
+#[pyfunction]
+fn decompile_path(regex_pattern: &str) -> PyResult<String> {
+    let body = regex_pattern.strip_prefix('^').unwrap_or(regex_pattern);
+    let body = body.strip_suffix('$').unwrap_or(body);
+
+    let mut template = String::with_capacity(body.len());
+    let mut chars = body.char_indices().peekable();
+    while let Some((pos, ch)) = chars.next() {
+        match ch {
+            '\\' => match chars.next() {
+                Some((_, escaped)) => template.push(escaped),
+                None => {
+                    return Err(pyo3::exceptions::PyValueError::new_err("Dangling escape at end of pattern"));
+                }
+            },
+            '(' => {
+                let Some(after_prefix) = body[pos..].strip_prefix("(?P<") else {
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "Unnamed capture group at position {}", pos
+                    )));
+                };
+                let name_end = after_prefix.find('>').ok_or_else(|| {
+                    pyo3::exceptions::PyValueError::new_err(format!("Unterminated group name at position {}", pos))
+                })?;
+                let param_name = &after_prefix[..name_end];
+                let group_start = pos + "(?P<".len() + name_end + 1;
+                let group_end = find_group_end(body, group_start).ok_or_else(|| {
+                    pyo3::exceptions::PyValueError::new_err(format!("Unbalanced group for parameter '{}'", param_name))
+                })?;
+
+                let group_regex = &body[group_start..group_end];
+                match convertor_name_for_regex(group_regex) {
+                    Some("str") => template.push_str(&format!("{{{}}}", param_name)),
+                    Some(convertor_name) => template.push_str(&format!("{{{}:{}}}", param_name, convertor_name)),
+                    // Custom regex constraints round-trip unless they would read as a type name
+                    None if !is_convertor_name(group_regex) => {
+                        template.push_str(&format!("{{{}:{}}}", param_name, group_regex))
+                    }
+                    None => {
+                        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                            "Unknown pattern for parameter '{}': {}",
+                            param_name,
+                            &body[group_start..group_end]
+                        )));
+                    }
+                }
+
+                // Skip past the closing parenthesis of the group
+                while chars.next_if(|&(idx, _)| idx <= group_end).is_some() {}
+            }
+            '.' | '*' | '+' | '?' | '|' | '[' | ']' | '{' | '}' | ')' | '^' | '$' => {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "Unexpected regex syntax '{}' at position {}", ch, pos
+                )));
+            }
+            _ => template.push(ch),
+        }
+    }
+
+    Ok(template)
+}
+
+/// Find the byte index of the parenthesis closing a group whose body starts at `start`
+fn find_group_end(pattern: &str, start: usize) -> Option<usize> {
+    let mut depth = 1usize;
+    let mut in_class = false;
+    let mut escaped = false;
+    for (offset, ch) in pattern[start..].char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match ch {
+            '\\' => escaped = true,
+            '[' if !in_class => in_class = true,
+            ']' if in_class => in_class = false,
+            '(' if !in_class => depth += 1,
+            ')' if !in_class => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(start + offset);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Map a built-in convertor regex back to its convertor type name
+fn convertor_name_for_regex(regex: &str) -> Option<&'static str> {
+    match regex {
+        STRING_REGEX => Some("str"),
+        PATH_REGEX => Some("path"),
+        INTEGER_REGEX => Some("int"),
+        FLOAT_REGEX => Some("float"),
+        UUID_REGEX => Some("uuid"),
+        _ => None,
+    }
+}
+
 /// Register all convertor functions and classes with Python
 pub fn register_convertors(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Register individual convertor classes
@@ -259,10 +523,12 @@ pub fn register_convertors(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()>
     m.add_class::<IntegerConvertor>()?;
     m.add_class::<FloatConvertor>()?;
     m.add_class::<UUIDConvertor>()?;
+    m.add_class::<RegexConvertor>()?;
     m.add_class::<Convertor>()?;
     
     // Register utility functions
     m.add_function(wrap_pyfunction!(compile_path, m)?)?;
+    m.add_function(wrap_pyfunction!(decompile_path, m)?)?;
     
     Ok(())
 }
@@ -27,6 +27,61 @@ fn cached_signature(py: Python, func: Bound<PyAny>) -> PyResult<Py<PyAny>> {
     Ok(signature.unbind())
 }
 
+/// Whether calling `func` produces a coroutine: async functions, partials of them,
+/// and objects with an async `__call__`
+#[pyfunction]
+fn is_async_callable(py: Python, func: Bound<PyAny>) -> PyResult<bool> {
+    // inspect unwraps partials of functions but not of objects with an async __call__
+    let partial_type = PyModule::import(py, "functools")?.getattr("partial")?;
+    let mut func = func;
+    while func.is_instance(&partial_type)? {
+        func = func.getattr("func")?;
+    }
+
+    let inspect_module = PyModule::import(py, "inspect")?;
+    let is_coroutine_function = inspect_module.getattr("iscoroutinefunction")?;
+    if is_coroutine_function.call1((&func,))?.is_truthy()? {
+        return Ok(true);
+    }
+    if func.is_instance_of::<PyType>() || inspect_module.getattr("isroutine")?.call1((&func,))?.is_truthy()? {
+        return Ok(false);
+    }
+    match func.getattr("__call__") {
+        Ok(call) => is_coroutine_function.call1((call,))?.is_truthy(),
+        Err(_) => Ok(false),
+    }
+}
+
+/// Cached signature plus what the container needs to call the function:
+/// {"signature": ..., "is_async": bool, "param_count": int}
+#[pyfunction(name = "di_cached_signature_with_metadata")]
+fn cached_signature_with_metadata<'py>(py: Python<'py>, func: Bound<'py, PyAny>) -> PyResult<Bound<'py, PyDict>> {
+    let is_async = is_async_callable(py, func.clone())?;
+    let signature = cached_signature(py, func)?;
+    let param_count = signature.bind(py).getattr("parameters")?.len()?;
+
+    let metadata = PyDict::new(py);
+    metadata.set_item("signature", signature)?;
+    metadata.set_item("is_async", is_async)?;
+    metadata.set_item("param_count", param_count)?;
+    Ok(metadata)
+}
+
+/// Call a factory with positional arguments. Async factories return their
+/// coroutine for the caller to await, as AsyncFactoryProvider does.
+#[pyfunction]
+#[pyo3(signature = (func, args, is_async=false))]
+fn di_call_factory(py: Python, func: Bound<PyAny>, args: Vec<Py<PyAny>>, is_async: bool) -> PyResult<Py<PyAny>> {
+    let result = func.call1(PyTuple::new(py, args)?)?;
+    if is_async && !result.hasattr("__await__")? {
+        return Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(format!(
+            "Async factory {} returned a non-awaitable value",
+            func.repr()?
+        )));
+    }
+    Ok(result.unbind())
+}
+
 #[pyclass]
 pub struct Provide {
     #[pyo3(get)]
@@ -469,6 +524,9 @@ pub fn register_di(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     // Register utility functions
     m.add_function(wrap_pyfunction!(cached_signature, m)?)?;
+    m.add_function(wrap_pyfunction!(cached_signature_with_metadata, m)?)?;
+    m.add_function(wrap_pyfunction!(is_async_callable, m)?)?;
+    m.add_function(wrap_pyfunction!(di_call_factory, m)?)?;
 
     Ok(())
 }
@@ -2,33 +2,89 @@ use parking_lot::Mutex;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 use pyo3_async_runtimes::tokio::get_runtime;
-use std::collections::HashMap;
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
 use tokio::sync::mpsc::{self, Sender};
+use tokio::sync::mpsc::error::TrySendError;
+
+use crate::templates::TemplateEngine;
 
 struct Listener {
     callback: Py<PyAny>,
     is_async: bool,
 }
 
+/// What `emit` does when an event's channel is full
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum BackpressureStrategy {
+    Drop,  // Discard the event and count it
+    Block, // Wait until the channel has room
+    Error, // Raise RuntimeError
+}
+
+impl BackpressureStrategy {
+    fn from_str(s: &str) -> PyResult<Self> {
+        match s {
+            "drop" => Ok(BackpressureStrategy::Drop),
+            "block" => Ok(BackpressureStrategy::Block),
+            "error" => Ok(BackpressureStrategy::Error),
+            _ => Err(pyo3::exceptions::PyValueError::new_err(
+                "Invalid backpressure strategy. Use 'drop', 'block', or 'error'",
+            )),
+        }
+    }
+}
+
+/// An emitted event kept for replay
+struct HistoryEntry {
+    event_name: String,
+    data: Py<PyDict>,
+    timestamp: f64, // Unix time in seconds
+}
+
 #[pyclass(subclass, name = "RustEventChannel")]
 struct EventChannel {
     channels: Arc<Mutex<HashMap<String, Sender<Py<PyDict>>>>>,
     listeners: Arc<Mutex<HashMap<String, Vec<Listener>>>>,
     // Buffer size for the channel
     buffer_size: usize,
+    backpressure_strategy: BackpressureStrategy,
+    // Events discarded per event name in "drop" mode
+    dropped_counts: Arc<Mutex<HashMap<String, u64>>>,
+    // JSON schemas that emitted data must satisfy, per event name
+    schemas: Arc<Mutex<HashMap<String, Value>>>,
+    // Events not delivered because they failed schema validation
+    rejected_counts: Arc<Mutex<HashMap<String, u64>>>,
+    record_history: bool,
+    max_history_size: usize,
+    // Emitted events, oldest first, when record_history is enabled
+    history: Arc<Mutex<VecDeque<HistoryEntry>>>,
 }
 
 #[pymethods]
 impl EventChannel {
     #[new]
-    #[pyo3(signature = (buffer_size=1000))]
-    fn new(buffer_size: usize) -> Self {
-        EventChannel {
+    #[pyo3(signature = (buffer_size=1000, backpressure_strategy="block", record_history=false, max_history_size=1000))]
+    fn new(
+        buffer_size: usize,
+        backpressure_strategy: &str,
+        record_history: bool,
+        max_history_size: usize,
+    ) -> PyResult<Self> {
+        Ok(EventChannel {
             channels: Arc::new(Mutex::new(HashMap::new())),
             listeners: Arc::new(Mutex::new(HashMap::new())),
             buffer_size,
-        }
+            backpressure_strategy: BackpressureStrategy::from_str(backpressure_strategy)?,
+            dropped_counts: Arc::new(Mutex::new(HashMap::new())),
+            schemas: Arc::new(Mutex::new(HashMap::new())),
+            rejected_counts: Arc::new(Mutex::new(HashMap::new())),
+            record_history,
+            max_history_size,
+            history: Arc::new(Mutex::new(VecDeque::new())),
+        })
     }
 
     fn register_listener(
@@ -101,22 +157,165 @@ impl EventChannel {
     }
 
     async fn emit(&self, event_name: String, data: Py<PyDict>) -> PyResult<()> {
+        let schema = self.schemas.lock().get(&event_name).cloned();
+        if let Some(schema) = schema {
+            let errors = Python::attach(|py| validate_data(data.bind(py), &schema))?;
+            if !errors.is_empty() {
+                *self.rejected_counts.lock().entry(event_name).or_insert(0) += 1;
+                return Ok(());
+            }
+        }
+
+        // Record before looking up listeners so late subscribers can catch up
+        if self.record_history && self.max_history_size > 0 {
+            let entry = HistoryEntry {
+                event_name: event_name.clone(),
+                data: Python::attach(|py| data.clone_ref(py)),
+                timestamp: unix_seconds(),
+            };
+            let mut history = self.history.lock();
+            if history.len() >= self.max_history_size {
+                history.pop_front();
+            }
+            history.push_back(entry);
+        }
+
         // Clone the sender before await to avoid holding the lock across await
         let tx_opt = {
             let channels = self.channels.lock();
             channels.get(&event_name).cloned()
         };
-        if let Some(tx) = tx_opt {
-            tx.send(data).await.map_err(|e| {
+        let Some(tx) = tx_opt else {
+            return Ok(());
+        };
+
+        if self.backpressure_strategy == BackpressureStrategy::Block {
+            return tx.send(data).await.map_err(|e| {
                 PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
                     "Channel send error: {}",
                     e
                 ))
-            })?;
+            });
+        }
+
+        match tx.try_send(data) {
+            Ok(()) => Ok(()),
+            Err(TrySendError::Full(_)) if self.backpressure_strategy == BackpressureStrategy::Drop => {
+                *self.dropped_counts.lock().entry(event_name).or_insert(0) += 1;
+                Ok(())
+            }
+            Err(TrySendError::Full(_)) => Err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
+                format!("Channel for event '{}' is full", event_name),
+            )),
+            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                "Channel send error: {}",
+                e
+            ))),
         }
+    }
+
+    /// Number of events queued for an event name but not yet dispatched
+    fn get_channel_depth(&self, event_name: &str) -> usize {
+        self.channels
+            .lock()
+            .get(event_name)
+            .map_or(0, |tx| tx.max_capacity() - tx.capacity())
+    }
+
+    /// Number of events discarded for an event name because its channel was full
+    fn get_dropped_count(&self, event_name: &str) -> u64 {
+        self.dropped_counts.lock().get(event_name).copied().unwrap_or(0)
+    }
+
+    /// Register a JSON schema that data emitted for an event must satisfy
+    fn register_event_schema(&self, event_name: String, schema: &Bound<'_, PyDict>) -> PyResult<()> {
+        let schema = TemplateEngine::python_dict_to_json(schema)?;
+        self.schemas.lock().insert(event_name, schema);
         Ok(())
     }
 
+    /// Validate data against an event's schema without emitting it
+    fn validate_event(&self, event_name: &str, data: &Bound<'_, PyDict>) -> PyResult<Vec<String>> {
+        let schema = self.schemas.lock().get(event_name).cloned();
+        match schema {
+            Some(schema) => validate_data(data, &schema),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Number of events not delivered because they failed schema validation
+    fn get_rejected_count(&self, event_name: &str) -> u64 {
+        self.rejected_counts.lock().get(event_name).copied().unwrap_or(0)
+    }
+
+    /// Re-emit recorded events to the current listeners, oldest first.
+    /// `since` is a Unix timestamp; `limit` keeps only the most recent matching events.
+    /// Events whose channel is missing or full are skipped. Returns the number re-emitted.
+    #[pyo3(signature = (event_name=None, since=None, limit=None))]
+    fn replay(
+        &self,
+        py: Python,
+        event_name: Option<&str>,
+        since: Option<f64>,
+        limit: Option<usize>,
+    ) -> usize {
+        let events: Vec<(String, Py<PyDict>)> = {
+            let history = self.history.lock();
+            let matching: Vec<&HistoryEntry> = history
+                .iter()
+                .filter(|entry| event_name.is_none_or(|name| entry.event_name == name))
+                .filter(|entry| since.is_none_or(|since| entry.timestamp >= since))
+                .collect();
+            let skip = limit.map_or(0, |limit| matching.len().saturating_sub(limit));
+            matching
+                .into_iter()
+                .skip(skip)
+                .map(|entry| (entry.event_name.clone(), entry.data.clone_ref(py)))
+                .collect()
+        };
+
+        let channels = self.channels.lock();
+        events
+            .into_iter()
+            .filter(|(name, data)| {
+                channels
+                    .get(name)
+                    .is_some_and(|tx| tx.try_send(data.clone_ref(py)).is_ok())
+            })
+            .count()
+    }
+
+    /// Recorded events as {"event_name", "data", "timestamp"} dicts, oldest first
+    #[pyo3(signature = (event_name=None))]
+    fn get_history<'py>(
+        &self,
+        py: Python<'py>,
+        event_name: Option<&str>,
+    ) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        let history = self.history.lock();
+        history
+            .iter()
+            .filter(|entry| event_name.is_none_or(|name| entry.event_name == name))
+            .map(|entry| {
+                let dict = PyDict::new(py);
+                dict.set_item("event_name", &entry.event_name)?;
+                dict.set_item("data", entry.data.bind(py))?;
+                dict.set_item("timestamp", entry.timestamp)?;
+                Ok(dict)
+            })
+            .collect()
+    }
+
+    /// Remove recorded events for one event name, or all of them
+    #[pyo3(signature = (event_name=None))]
+    fn clear_history(&self, event_name: Option<&str>) {
+        let mut history = self.history.lock();
+        match event_name {
+            Some(name) => history.retain(|entry| entry.event_name != name),
+            None => history.clear(),
+        }
+    }
+
     async fn cleanup(&self) -> PyResult<()> {
         {
             let mut channels = self.channels.lock();
@@ -126,10 +325,126 @@ impl EventChannel {
             let mut listeners = self.listeners.lock();
             listeners.clear();
         }
+        {
+            let mut dropped_counts = self.dropped_counts.lock();
+            dropped_counts.clear();
+        }
+        {
+            let mut schemas = self.schemas.lock();
+            schemas.clear();
+        }
+        {
+            let mut rejected_counts = self.rejected_counts.lock();
+            rejected_counts.clear();
+        }
+        {
+            let mut history = self.history.lock();
+            history.clear();
+        }
         Ok(())
     }
 }
 
+/// Validate event data against a schema, returning one message per violation
+fn validate_data(data: &Bound<'_, PyDict>, schema: &Value) -> PyResult<Vec<String>> {
+    let value = TemplateEngine::python_dict_to_json(data)?;
+    let mut errors = Vec::new();
+    validate_value(&value, schema, "$", &mut errors);
+    Ok(errors)
+}
+
+/// Minimal JSON Schema check covering type, enum, required, properties,
+/// additionalProperties and items
+fn validate_value(value: &Value, schema: &Value, path: &str, errors: &mut Vec<String>) {
+    let Some(schema) = schema.as_object() else {
+        return;
+    };
+
+    if let Some(expected) = schema.get("type") {
+        let allowed: Vec<&str> = match expected {
+            Value::String(t) => vec![t.as_str()],
+            Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        if !allowed.is_empty() && !allowed.iter().any(|t| matches_type(value, t)) {
+            errors.push(format!(
+                "{}: expected type '{}', got '{}'",
+                path,
+                allowed.join("' or '"),
+                type_name(value)
+            ));
+            return;
+        }
+    }
+
+    if let Some(Value::Array(options)) = schema.get("enum")
+        && !options.contains(value)
+    {
+        errors.push(format!("{}: value {} is not one of the allowed values", path, value));
+    }
+
+    if let Value::Object(fields) = value {
+        if let Some(Value::Array(required)) = schema.get("required") {
+            for name in required.iter().filter_map(Value::as_str) {
+                if !fields.contains_key(name) {
+                    errors.push(format!("{}: missing required field '{}'", path, name));
+                }
+            }
+        }
+
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for (name, field_value) in fields {
+            match properties.and_then(|p| p.get(name)) {
+                Some(field_schema) => {
+                    validate_value(field_value, field_schema, &format!("{}.{}", path, name), errors)
+                }
+                None if schema.get("additionalProperties") == Some(&Value::Bool(false)) => {
+                    errors.push(format!("{}: unexpected field '{}'", path, name));
+                }
+                None => {}
+            }
+        }
+    }
+
+    if let (Value::Array(items), Some(item_schema)) = (value, schema.get("items")) {
+        for (index, item) in items.iter().enumerate() {
+            validate_value(item, item_schema, &format!("{}[{}]", path, index), errors);
+        }
+    }
+}
+
+fn matches_type(value: &Value, expected: &str) -> bool {
+    match expected {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Object(_) => "object",
+        Value::Array(_) => "array",
+        Value::String(_) => "string",
+        Value::Number(n) if n.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::Bool(_) => "boolean",
+        Value::Null => "null",
+    }
+}
+
+fn unix_seconds() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs_f64())
+        .unwrap_or(0.0)
+}
+
 pub fn register_events(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Register DI classes
     m.add_class::<EventChannel>()?;
@@ -0,0 +1,241 @@
+use parking_lot::Mutex;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+/// Batches and caches loads to avoid N+1 queries in field resolvers.
+///
+/// Every `load` made during one event loop iteration is collected, and
+/// `batch_fn(keys)` is called once on the next iteration. `batch_fn` returns
+/// (or resolves to) one value per key, in key order; an exception instance in
+/// place of a value fails only that key's load.
+#[pyclass]
+pub struct DataLoader {
+    batch_fn: Py<PyAny>,
+    max_batch_size: usize,
+    // Futures per key, kept for the loader's lifetime when caching is enabled
+    cache: Option<Py<PyDict>>,
+    // Keys waiting for the next dispatch with the futures to resolve
+    pending: Mutex<Vec<(Py<PyAny>, Py<PyAny>)>>,
+    dispatch_scheduled: Mutex<bool>,
+}
+
+#[pymethods]
+impl DataLoader {
+    #[new]
+    #[pyo3(signature = (batch_fn, max_batch_size=100, cache=true))]
+    fn new(py: Python, batch_fn: Py<PyAny>, max_batch_size: usize, cache: bool) -> PyResult<Self> {
+        if max_batch_size == 0 {
+            return Err(PyValueError::new_err("max_batch_size must be greater than 0"));
+        }
+        if !batch_fn.bind(py).is_callable() {
+            return Err(PyTypeError::new_err("batch_fn must be callable"));
+        }
+        Ok(Self {
+            batch_fn,
+            max_batch_size,
+            cache: cache.then(|| PyDict::new(py).unbind()),
+            pending: Mutex::new(Vec::new()),
+            dispatch_scheduled: Mutex::new(false),
+        })
+    }
+
+    /// Load a value by key; returns an awaitable resolved by the next batch
+    fn load<'py>(slf: &Bound<'py, Self>, key: Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let py = slf.py();
+        let this = slf.borrow();
+
+        if let Some(cache) = &this.cache
+            && let Some(future) = cache.bind(py).get_item(&key)?
+        {
+            return Ok(future);
+        }
+
+        let event_loop = py.import("asyncio")?.call_method0("get_running_loop")?;
+        let future = event_loop.call_method0("create_future")?;
+        if let Some(cache) = &this.cache {
+            cache.bind(py).set_item(&key, &future)?;
+        }
+        this.pending.lock().push((key.unbind(), future.clone().unbind()));
+
+        let mut scheduled = this.dispatch_scheduled.lock();
+        if !*scheduled {
+            event_loop.call_method1("call_soon", (slf.getattr("_dispatch")?,))?;
+            *scheduled = true;
+        }
+        Ok(future)
+    }
+
+    /// Load several keys; returns an awaitable of the values in key order
+    fn load_many<'py>(slf: &Bound<'py, Self>, keys: Vec<Bound<'py, PyAny>>) -> PyResult<Bound<'py, PyAny>> {
+        let py = slf.py();
+        let futures = keys
+            .into_iter()
+            .map(|key| Self::load(slf, key))
+            .collect::<PyResult<Vec<_>>>()?;
+        py.import("asyncio")?.call_method1("gather", PyList::new(py, futures)?.to_tuple())
+    }
+
+    /// Forget the cached value for a key
+    fn clear(&self, py: Python, key: Bound<'_, PyAny>) -> PyResult<()> {
+        if let Some(cache) = &self.cache {
+            let cache = cache.bind(py);
+            if cache.contains(&key)? {
+                cache.del_item(&key)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Forget all cached values
+    fn clear_all(&self, py: Python) {
+        if let Some(cache) = &self.cache {
+            cache.bind(py).clear();
+        }
+    }
+
+    /// Send all pending keys to `batch_fn`, split into batches of `max_batch_size`
+    #[pyo3(name = "_dispatch")]
+    fn dispatch(&self, py: Python) -> PyResult<()> {
+        let pending = {
+            let mut scheduled = self.dispatch_scheduled.lock();
+            *scheduled = false;
+            std::mem::take(&mut *self.pending.lock())
+        };
+
+        let mut pending = pending.into_iter().peekable();
+        while pending.peek().is_some() {
+            let (keys, futures): (Vec<Py<PyAny>>, Vec<Py<PyAny>>) =
+                pending.by_ref().take(self.max_batch_size).unzip();
+            let batch = Batch {
+                keys,
+                futures,
+                cache: self.cache.as_ref().map(|cache| cache.clone_ref(py)),
+            };
+            // A failed batch must not leave the loads of later batches unresolved
+            let fallback = batch.clone_ref(py);
+            if let Err(err) = self.run_batch(py, batch) {
+                fallback.fail(py, err)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "DataLoader(max_batch_size={}, cache={})",
+            self.max_batch_size,
+            self.cache.is_some()
+        )
+    }
+}
+
+impl DataLoader {
+    fn run_batch(&self, py: Python, batch: Batch) -> PyResult<()> {
+        let keys = PyList::new(py, batch.keys.iter().map(|key| key.bind(py)))?;
+        let result = match self.batch_fn.bind(py).call1((keys,)) {
+            Ok(result) => result,
+            Err(err) => return batch.fail(py, err),
+        };
+
+        if !result.hasattr("__await__")? {
+            return batch.resolve(py, &result);
+        }
+
+        let task = py.import("asyncio")?.call_method1("ensure_future", (result,))?;
+        task.call_method1("add_done_callback", (Bound::new(py, batch)?,))?;
+        Ok(())
+    }
+}
+
+/// Keys sent to one `batch_fn` call and the futures awaiting their values.
+/// Used directly as the done callback when `batch_fn` is async.
+#[pyclass]
+struct Batch {
+    keys: Vec<Py<PyAny>>,
+    futures: Vec<Py<PyAny>>,
+    cache: Option<Py<PyDict>>,
+}
+
+#[pymethods]
+impl Batch {
+    fn __call__(&self, py: Python, task: Bound<'_, PyAny>) -> PyResult<()> {
+        // result() re-raises the batch function's exception, including cancellation
+        match task.call_method0("result") {
+            Ok(values) => self.resolve(py, &values),
+            Err(err) => self.fail(py, err),
+        }
+    }
+}
+
+impl Batch {
+    fn clone_ref(&self, py: Python) -> Self {
+        Self {
+            keys: self.keys.iter().map(|key| key.clone_ref(py)).collect(),
+            futures: self.futures.iter().map(|future| future.clone_ref(py)).collect(),
+            cache: self.cache.as_ref().map(|cache| cache.clone_ref(py)),
+        }
+    }
+
+    fn resolve(&self, py: Python, values: &Bound<'_, PyAny>) -> PyResult<()> {
+        let values: Vec<Bound<'_, PyAny>> = match values.try_iter() {
+            Ok(iter) => iter.collect::<PyResult<_>>()?,
+            Err(_) => {
+                return self.fail(py, PyTypeError::new_err("DataLoader batch_fn must return a list of values"));
+            }
+        };
+        if values.len() != self.keys.len() {
+            return self.fail(
+                py,
+                PyValueError::new_err(format!(
+                    "DataLoader batch_fn returned {} values for {} keys",
+                    values.len(),
+                    self.keys.len()
+                )),
+            );
+        }
+
+        for ((key, future), value) in self.keys.iter().zip(&self.futures).zip(values) {
+            let future = future.bind(py);
+            if future.call_method0("done")?.is_truthy()? {
+                continue;
+            }
+            if value.is_instance_of::<pyo3::exceptions::PyBaseException>() {
+                self.uncache(py, key)?;
+                future.call_method1("set_exception", (value,))?;
+            } else {
+                future.call_method1("set_result", (value,))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Fail every load in the batch and drop the keys from the cache so they can be retried
+    fn fail(&self, py: Python, err: PyErr) -> PyResult<()> {
+        let exc = err.into_value(py);
+        for (key, future) in self.keys.iter().zip(&self.futures) {
+            self.uncache(py, key)?;
+            let future = future.bind(py);
+            if !future.call_method0("done")?.is_truthy()? {
+                future.call_method1("set_exception", (exc.bind(py),))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn uncache(&self, py: Python, key: &Py<PyAny>) -> PyResult<()> {
+        if let Some(cache) = &self.cache {
+            let cache = cache.bind(py);
+            if cache.contains(key)? {
+                cache.del_item(key)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Register GraphQL helpers with Python
+pub fn register_graphql(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<DataLoader>()?;
+    Ok(())
+}
@@ -0,0 +1,708 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chrono::{DateTime, NaiveDateTime};
+use hmac::{Hmac, Mac};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use sha2::Sha256;
+use std::collections::HashMap;
+
+/// Builder for `Set-Cookie` response header values
+#[pyclass]
+#[derive(Default)]
+pub struct CookieBuilder {
+    name: String,
+    value: String,
+    domain: Option<String>,
+    path: Option<String>,
+    max_age: Option<i64>,
+    expires: Option<u64>,
+    secure: bool,
+    http_only: bool,
+    same_site: Option<String>,
+}
+
+#[pymethods]
+impl CookieBuilder {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn name<'py>(mut slf: PyRefMut<'py, Self>, n: &str) -> PyRefMut<'py, Self> {
+        slf.name = n.to_string();
+        slf
+    }
+
+    fn value<'py>(mut slf: PyRefMut<'py, Self>, v: &str) -> PyRefMut<'py, Self> {
+        slf.value = v.to_string();
+        slf
+    }
+
+    fn domain<'py>(mut slf: PyRefMut<'py, Self>, d: &str) -> PyRefMut<'py, Self> {
+        slf.domain = Some(d.to_string());
+        slf
+    }
+
+    fn path<'py>(mut slf: PyRefMut<'py, Self>, p: &str) -> PyRefMut<'py, Self> {
+        slf.path = Some(p.to_string());
+        slf
+    }
+
+    fn max_age(mut slf: PyRefMut<'_, Self>, seconds: i64) -> PyRefMut<'_, Self> {
+        slf.max_age = Some(seconds);
+        slf
+    }
+
+    /// Set the expiry as a Unix timestamp in seconds
+    fn expires(mut slf: PyRefMut<'_, Self>, timestamp: u64) -> PyRefMut<'_, Self> {
+        slf.expires = Some(timestamp);
+        slf
+    }
+
+    fn secure(mut slf: PyRefMut<'_, Self>) -> PyRefMut<'_, Self> {
+        slf.secure = true;
+        slf
+    }
+
+    fn http_only(mut slf: PyRefMut<'_, Self>) -> PyRefMut<'_, Self> {
+        slf.http_only = true;
+        slf
+    }
+
+    /// Set the SameSite policy: "Strict", "Lax" or "None"
+    fn same_site<'py>(mut slf: PyRefMut<'py, Self>, policy: &str) -> PyResult<PyRefMut<'py, Self>> {
+        let normalized = match policy.to_lowercase().as_str() {
+            "strict" => "Strict",
+            "lax" => "Lax",
+            "none" => "None",
+            _ => {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "Invalid SameSite policy. Use 'Strict', 'Lax', or 'None'",
+                ));
+            }
+        };
+        slf.same_site = Some(normalized.to_string());
+        Ok(slf)
+    }
+
+    /// Build the `Set-Cookie` header value
+    fn build(&self) -> PyResult<String> {
+        if self.name.is_empty() || !self.name.bytes().all(is_token_byte) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Invalid cookie name: {:?}",
+                self.name
+            )));
+        }
+        // Quoting cannot make control characters safe, and CR/LF would end the header
+        if self.value.chars().any(char::is_control) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Invalid cookie value: {:?}",
+                self.value
+            )));
+        }
+        if self.same_site.as_deref() == Some("None") && !self.secure {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "SameSite=None requires the Secure attribute",
+            ));
+        }
+
+        let mut cookie = format!("{}={}", self.name, quote_cookie_value(&self.value));
+
+        if let Some(ref domain) = self.domain {
+            cookie.push_str(&format!("; Domain={}", validate_attribute("Domain", domain)?));
+        }
+        if let Some(ref path) = self.path {
+            cookie.push_str(&format!("; Path={}", validate_attribute("Path", path)?));
+        }
+        if let Some(max_age) = self.max_age {
+            cookie.push_str(&format!("; Max-Age={}", max_age));
+        }
+        if let Some(expires) = self.expires {
+            cookie.push_str(&format!("; Expires={}", format_http_date(expires)?));
+        }
+        if self.secure {
+            cookie.push_str("; Secure");
+        }
+        if self.http_only {
+            cookie.push_str("; HttpOnly");
+        }
+        if let Some(ref same_site) = self.same_site {
+            cookie.push_str(&format!("; SameSite={}", same_site));
+        }
+
+        Ok(cookie)
+    }
+}
+
+/// RFC 7230 token characters, used for cookie names
+fn is_token_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b)
+}
+
+/// Quote a cookie value when it contains characters outside RFC 6265 cookie-octets
+fn quote_cookie_value(value: &str) -> String {
+    let needs_quoting = value
+        .bytes()
+        .any(|b| b <= b' ' || b >= 0x7f || matches!(b, b'"' | b',' | b';' | b'\\'));
+    if !needs_quoting {
+        return value.to_string();
+    }
+
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for ch in value.chars() {
+        if ch == '"' || ch == '\\' {
+            quoted.push('\\');
+        }
+        quoted.push(ch);
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Reject attribute values that would break out of the header
+fn validate_attribute<'a>(attribute: &str, value: &'a str) -> PyResult<&'a str> {
+    if value.chars().any(|c| c == ';' || c.is_control()) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Invalid cookie {} attribute: {:?}",
+            attribute, value
+        )));
+    }
+    Ok(value)
+}
+
+/// Length of an unpadded base64url HMAC-SHA256 tag
+const COOKIE_SIGNATURE_LEN: usize = 43;
+
+/// Key `parse_signed_cookies` lists failed cookies under, so no cookie may use it as a name
+const INVALID_COOKIES_KEY: &str = "_invalid";
+
+fn check_cookie_secret(secret: &str) -> PyResult<()> {
+    if secret.is_empty() {
+        return Err(pyo3::exceptions::PyValueError::new_err("Cookie signing secret must not be empty"));
+    }
+    Ok(())
+}
+
+/// MAC over `name=value`, so a signed value cannot be replayed under another cookie name
+fn cookie_mac(name: &str, value: &str, secret: &str) -> PyResult<Hmac<Sha256>> {
+    check_cookie_secret(secret)?;
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+    mac.update(name.as_bytes());
+    mac.update(b"=");
+    mac.update(value.as_bytes());
+    Ok(mac)
+}
+
+/// Sign a cookie value as `value.signature` using HMAC-SHA256 (base64url).
+/// The name `_invalid` is reserved by `parse_signed_cookies` and rejected.
+#[pyfunction]
+fn sign_cookie(name: &str, value: &str, secret: &str) -> PyResult<String> {
+    if name == INVALID_COOKIES_KEY {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Cookie name '{}' is reserved for signature failures",
+            INVALID_COOKIES_KEY
+        )));
+    }
+    let signature = cookie_mac(name, value, secret)?.finalize().into_bytes();
+    Ok(format!("{}.{}", value, URL_SAFE_NO_PAD.encode(signature)))
+}
+
+/// Verify a signed cookie value in constant time and return the original value
+#[pyfunction]
+fn verify_cookie(name: &str, raw_value: &str, secret: &str) -> PyResult<String> {
+    let invalid = || pyo3::exceptions::PyValueError::new_err(format!("Invalid signature for cookie '{}'", name));
+    let (value, signature) = raw_value.rsplit_once('.').ok_or_else(invalid)?;
+    let signature = URL_SAFE_NO_PAD.decode(signature).map_err(|_| invalid())?;
+    cookie_mac(name, value, secret)?
+        .verify_slice(&signature)
+        .map_err(|_| invalid())?;
+    Ok(value.to_string())
+}
+
+/// Verify every signed cookie in a `Cookie` header. Returns a dict of verified
+/// values plus an `_invalid` list of cookie names whose signatures did not match.
+/// Unsigned cookies are skipped, as is any cookie named `_invalid`, which
+/// `sign_cookie` never produces.
+#[pyfunction]
+fn parse_signed_cookies<'py>(py: Python<'py>, cookie_header: &str, secret: &str) -> PyResult<Bound<'py, PyDict>> {
+    check_cookie_secret(secret)?;
+    let cookies = PyDict::new(py);
+    let invalid = PyList::empty(py);
+
+    for pair in cookie_header.split(';') {
+        let Some((name, raw_value)) = pair.split_once('=') else {
+            continue;
+        };
+        let name = name.trim();
+        let raw_value = unquote_cookie_value(raw_value.trim());
+        let looks_signed = raw_value
+            .rsplit_once('.')
+            .is_some_and(|(_, signature)| signature.len() == COOKIE_SIGNATURE_LEN);
+        if name.is_empty() || name == INVALID_COOKIES_KEY || !looks_signed {
+            continue;
+        }
+
+        match verify_cookie(name, &raw_value, secret) {
+            Ok(value) => cookies.set_item(name, value)?,
+            Err(_) => invalid.append(name)?,
+        }
+    }
+
+    cookies.set_item(INVALID_COOKIES_KEY, invalid)?;
+    Ok(cookies)
+}
+
+/// Reverse `quote_cookie_value` for a value received in a `Cookie` header
+fn unquote_cookie_value(value: &str) -> String {
+    let Some(inner) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) else {
+        return value.to_string();
+    };
+    let mut unquoted = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unquoted.extend(chars.next()),
+            c => unquoted.push(c),
+        }
+    }
+    unquoted
+}
+
+/// Referrer-Policy values defined by the W3C Referrer Policy spec
+const REFERRER_POLICIES: [&str; 8] = [
+    "no-referrer",
+    "no-referrer-when-downgrade",
+    "origin",
+    "origin-when-cross-origin",
+    "same-origin",
+    "strict-origin",
+    "strict-origin-when-cross-origin",
+    "unsafe-url",
+];
+
+/// Security response headers built from a preset and optional overrides
+#[pyclass]
+pub struct SecurityHeaderPreset {
+    hsts_max_age: Option<u64>,
+    hsts_include_subdomains: bool,
+    hsts_preload: bool,
+    nosniff: bool,
+    frame_options: Option<String>,
+    csp: Option<String>,
+    referrer_policy: Option<String>,
+}
+
+#[pymethods]
+impl SecurityHeaderPreset {
+    /// Same as `standard()`
+    #[new]
+    fn new() -> Self {
+        Self::standard()
+    }
+
+    /// Maximum security: long HSTS with preload, no framing, locked-down CSP, no referrer
+    #[staticmethod]
+    fn strict() -> Self {
+        Self {
+            hsts_max_age: Some(63_072_000),
+            hsts_include_subdomains: true,
+            hsts_preload: true,
+            nosniff: true,
+            frame_options: Some("DENY".to_string()),
+            csp: Some(
+                "default-src 'none'; script-src 'self'; style-src 'self'; img-src 'self'; \
+                 connect-src 'self'; font-src 'self'; base-uri 'none'; form-action 'self'; \
+                 frame-ancestors 'none'"
+                    .to_string(),
+            ),
+            referrer_policy: Some("no-referrer".to_string()),
+        }
+    }
+
+    /// Reasonable defaults for most applications
+    #[staticmethod]
+    fn standard() -> Self {
+        Self {
+            hsts_max_age: Some(31_536_000),
+            hsts_include_subdomains: true,
+            hsts_preload: false,
+            nosniff: true,
+            frame_options: Some("SAMEORIGIN".to_string()),
+            csp: Some("default-src 'self'; frame-ancestors 'self'".to_string()),
+            referrer_policy: Some("strict-origin-when-cross-origin".to_string()),
+        }
+    }
+
+    /// Minimal restrictions: only MIME sniffing protection and the browser-default referrer policy
+    #[staticmethod]
+    fn permissive() -> Self {
+        Self {
+            hsts_max_age: None,
+            hsts_include_subdomains: false,
+            hsts_preload: false,
+            nosniff: true,
+            frame_options: None,
+            csp: None,
+            referrer_policy: Some("strict-origin-when-cross-origin".to_string()),
+        }
+    }
+
+    /// Set the Strict-Transport-Security max-age; 0 tells browsers to forget the policy
+    fn hsts_max_age(mut slf: PyRefMut<'_, Self>, seconds: u64) -> PyRefMut<'_, Self> {
+        slf.hsts_max_age = Some(seconds);
+        slf
+    }
+
+    fn csp<'py>(mut slf: PyRefMut<'py, Self>, policy: &str) -> PyResult<PyRefMut<'py, Self>> {
+        if policy.chars().any(char::is_control) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "Content-Security-Policy must not contain control characters",
+            ));
+        }
+        slf.csp = Some(policy.to_string());
+        Ok(slf)
+    }
+
+    /// Set X-Frame-Options: "DENY" or "SAMEORIGIN"
+    fn frame_options<'py>(mut slf: PyRefMut<'py, Self>, mode: &str) -> PyResult<PyRefMut<'py, Self>> {
+        let normalized = match mode.to_uppercase().as_str() {
+            "DENY" => "DENY",
+            "SAMEORIGIN" => "SAMEORIGIN",
+            _ => {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "Invalid X-Frame-Options mode. Use 'DENY' or 'SAMEORIGIN'",
+                ));
+            }
+        };
+        slf.frame_options = Some(normalized.to_string());
+        Ok(slf)
+    }
+
+    fn referrer_policy<'py>(mut slf: PyRefMut<'py, Self>, policy: &str) -> PyResult<PyRefMut<'py, Self>> {
+        let policy = policy.to_lowercase();
+        if !REFERRER_POLICIES.contains(&policy.as_str()) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Invalid Referrer-Policy: {}",
+                policy
+            )));
+        }
+        slf.referrer_policy = Some(policy);
+        Ok(slf)
+    }
+
+    /// Header name/value pairs for this configuration
+    fn build(&self) -> Vec<(String, String)> {
+        let mut headers = Vec::with_capacity(5);
+        if let Some(max_age) = self.hsts_max_age {
+            let mut value = format!("max-age={}", max_age);
+            if self.hsts_include_subdomains {
+                value.push_str("; includeSubDomains");
+            }
+            if self.hsts_preload {
+                value.push_str("; preload");
+            }
+            headers.push(("Strict-Transport-Security".to_string(), value));
+        }
+        if self.nosniff {
+            headers.push(("X-Content-Type-Options".to_string(), "nosniff".to_string()));
+        }
+        if let Some(ref mode) = self.frame_options {
+            headers.push(("X-Frame-Options".to_string(), mode.clone()));
+        }
+        if let Some(ref policy) = self.csp {
+            headers.push(("Content-Security-Policy".to_string(), policy.clone()));
+        }
+        if let Some(ref policy) = self.referrer_policy {
+            headers.push(("Referrer-Policy".to_string(), policy.clone()));
+        }
+        headers
+    }
+
+    /// Add the security headers to a response header list, keeping any values it already sets
+    fn inject_into_response(&self, mut response_headers: Vec<(String, String)>) -> Vec<(String, String)> {
+        for (name, value) in self.build() {
+            if !response_headers
+                .iter()
+                .any(|(existing, _)| existing.eq_ignore_ascii_case(&name))
+            {
+                response_headers.push((name, value));
+            }
+        }
+        response_headers
+    }
+}
+
+/// IMF-fixdate, the preferred HTTP-date format (RFC 7231 section 7.1.1.1)
+const IMF_FIXDATE_FORMAT: &str = "%a, %d %b %Y %H:%M:%S GMT";
+/// Obsolete formats recipients must still accept
+const RFC850_DATE_FORMAT: &str = "%A, %d-%b-%y %H:%M:%S GMT";
+const ASCTIME_DATE_FORMAT: &str = "%a %b %e %H:%M:%S %Y";
+
+/// Parse an HTTP-date in IMF-fixdate, RFC 850 or asctime format into a Unix timestamp
+#[pyfunction]
+fn parse_http_date(date_str: &str) -> PyResult<u64> {
+    let date_str = date_str.trim();
+    [IMF_FIXDATE_FORMAT, RFC850_DATE_FORMAT, ASCTIME_DATE_FORMAT]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(date_str, format).ok())
+        .and_then(|date| u64::try_from(date.and_utc().timestamp()).ok())
+        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(format!("Invalid HTTP date: {}", date_str)))
+}
+
+/// Format a Unix timestamp as an IMF-fixdate
+#[pyfunction]
+fn format_http_date(timestamp: u64) -> PyResult<String> {
+    let date = i64::try_from(timestamp)
+        .ok()
+        .and_then(|secs| DateTime::from_timestamp(secs, 0))
+        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("Timestamp out of range"))?;
+    Ok(date.format(IMF_FIXDATE_FORMAT).to_string())
+}
+
+/// Whether a conditional GET can be answered with 304 Not Modified.
+/// An unparseable `If-Modified-Since` is ignored, as RFC 7232 requires.
+#[pyfunction]
+fn is_not_modified(last_modified: u64, if_modified_since: &str) -> PyResult<bool> {
+    Ok(parse_http_date(if_modified_since).is_ok_and(|since| last_modified <= since))
+}
+
+/// Build a `Vary` header value, dropping case-insensitive duplicates
+#[pyfunction]
+fn build_vary_header(headers: Vec<String>) -> String {
+    join_vary(headers.iter().map(String::as_str))
+}
+
+/// Split a `Vary` header value into lowercase header names
+#[pyfunction]
+fn parse_vary_header(vary: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for name in split_vary(vary) {
+        let name = name.to_lowercase();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+/// Merge two `Vary` header values
+#[pyfunction]
+fn merge_vary_headers(a: &str, b: &str) -> String {
+    join_vary(split_vary(a).chain(split_vary(b)))
+}
+
+/// Return the request headers that the `Vary` directive says affect the response
+#[pyfunction]
+fn should_vary_on(vary: &str, request_headers: Vec<String>) -> Vec<String> {
+    let varied = parse_vary_header(vary);
+    if varied.iter().any(|name| name == "*") {
+        return request_headers;
+    }
+    request_headers
+        .into_iter()
+        .filter(|header| varied.contains(&header.trim().to_lowercase()))
+        .collect()
+}
+
+fn split_vary(vary: &str) -> impl Iterator<Item = &str> {
+    vary.split(',').map(str::trim).filter(|name| !name.is_empty())
+}
+
+/// Join header names into a `Vary` value, keeping the first spelling of each name.
+/// A `*` anywhere means the response varies on everything.
+fn join_vary<'a>(names: impl Iterator<Item = &'a str>) -> String {
+    let mut kept: Vec<&str> = Vec::new();
+    for name in names.map(str::trim).filter(|name| !name.is_empty()) {
+        if name == "*" {
+            return "*".to_string();
+        }
+        if !kept.iter().any(|existing| existing.eq_ignore_ascii_case(name)) {
+            kept.push(name);
+        }
+    }
+    kept.join(", ")
+}
+
+/// Request pseudo-headers defined by RFC 9113 section 8.3.1
+const HTTP2_REQUEST_PSEUDO_HEADERS: [&str; 4] = [":method", ":scheme", ":authority", ":path"];
+/// Connection-specific headers that are not allowed in HTTP/2 (RFC 9113 section 8.2.2)
+const HTTP2_CONNECTION_HEADERS: [&str; 5] = ["connection", "keep-alive", "proxy-connection", "transfer-encoding", "upgrade"];
+
+/// Separate HTTP/2 pseudo-headers from regular headers.
+/// Pseudo-headers must come first and appear once; repeated regular headers are
+/// joined with ", " (or "; " for cookie).
+#[pyfunction]
+fn split_http2_headers<'py>(
+    py: Python<'py>,
+    headers: Vec<(String, String)>,
+) -> PyResult<(Bound<'py, PyDict>, Bound<'py, PyDict>)> {
+    let pseudo = PyDict::new(py);
+    let regular = PyDict::new(py);
+    let mut combined: Vec<(String, String)> = Vec::new();
+
+    for (name, value) in headers {
+        let name = name.to_ascii_lowercase();
+        if name.starts_with(':') {
+            if !combined.is_empty() {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "Pseudo-header {} appears after a regular header",
+                    name
+                )));
+            }
+            if pseudo.contains(&name)? {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "Duplicate pseudo-header: {}",
+                    name
+                )));
+            }
+            pseudo.set_item(name, value)?;
+        } else if let Some((_, existing)) = combined.iter_mut().find(|(existing, _)| *existing == name) {
+            existing.push_str(if name == "cookie" { "; " } else { ", " });
+            existing.push_str(&value);
+        } else {
+            combined.push((name, value));
+        }
+    }
+
+    for (name, value) in combined {
+        regular.set_item(name, value)?;
+    }
+    Ok((pseudo, regular))
+}
+
+/// Check HTTP/2 pseudo-headers of a request (or a response, when `:status` is present)
+/// and return one message per problem
+#[pyfunction]
+fn validate_http2_pseudo_headers(pseudo_headers: HashMap<String, String>) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    if let Some(status) = pseudo_headers.get(":status") {
+        if status.len() != 3 || !status.parse::<u16>().is_ok_and(|code| (100..=599).contains(&code)) {
+            errors.push(format!("Invalid :status value: {}", status));
+        }
+        let mut extra: Vec<&String> = pseudo_headers.keys().filter(|name| *name != ":status").collect();
+        extra.sort();
+        for name in extra {
+            errors.push(format!("Pseudo-header {} is not allowed in a response", name));
+        }
+        return errors;
+    }
+
+    let mut unknown: Vec<&String> = pseudo_headers
+        .keys()
+        .filter(|name| !HTTP2_REQUEST_PSEUDO_HEADERS.contains(&name.as_str()))
+        .collect();
+    unknown.sort();
+    for name in unknown {
+        errors.push(format!("Unknown pseudo-header: {}", name));
+    }
+
+    let Some(method) = pseudo_headers.get(":method") else {
+        errors.push("Missing required pseudo-header: :method".to_string());
+        return errors;
+    };
+    if method.is_empty() || !method.bytes().all(is_token_byte) {
+        errors.push(format!("Invalid :method value: {}", method));
+    }
+
+    // CONNECT carries only :method and :authority
+    if method == "CONNECT" {
+        if !pseudo_headers.contains_key(":authority") {
+            errors.push("Missing required pseudo-header: :authority".to_string());
+        }
+        for name in [":scheme", ":path"] {
+            if pseudo_headers.contains_key(name) {
+                errors.push(format!("Pseudo-header {} is not allowed with CONNECT", name));
+            }
+        }
+        return errors;
+    }
+
+    for name in [":scheme", ":path"] {
+        if !pseudo_headers.contains_key(name) {
+            errors.push(format!("Missing required pseudo-header: {}", name));
+        }
+    }
+    if let Some(path) = pseudo_headers.get(":path") {
+        let asterisk_form = method == "OPTIONS" && path == "*";
+        if !path.starts_with('/') && !asterisk_form {
+            errors.push(format!("Invalid :path value: {}", path));
+        }
+    }
+    errors
+}
+
+/// Build an HTTP/2 request header list: pseudo-headers first, then lowercase regular
+/// headers with connection-specific ones and Host (replaced by :authority) removed
+#[pyfunction]
+#[pyo3(signature = (method, path, authority, regular_headers, scheme="https"))]
+fn build_http2_request_headers(
+    method: &str,
+    path: &str,
+    authority: &str,
+    regular_headers: &Bound<'_, PyDict>,
+    scheme: &str,
+) -> PyResult<Vec<(String, String)>> {
+    let mut headers = vec![
+        (":method".to_string(), method.to_string()),
+        (":scheme".to_string(), scheme.to_string()),
+        (":authority".to_string(), authority.to_string()),
+        (":path".to_string(), path.to_string()),
+    ];
+
+    let errors = validate_http2_pseudo_headers(headers.iter().cloned().collect());
+    if !errors.is_empty() {
+        return Err(pyo3::exceptions::PyValueError::new_err(errors.join("; ")));
+    }
+
+    for (name, value) in regular_headers.iter() {
+        let name = name.extract::<String>()?.to_ascii_lowercase();
+        let value = value.extract::<String>()?;
+        if name.starts_with(':') {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Pseudo-header {} is not allowed in regular headers",
+                name
+            )));
+        }
+        if name == "host" || HTTP2_CONNECTION_HEADERS.contains(&name.as_str()) {
+            continue;
+        }
+        // TE is only permitted with the value "trailers"
+        if name == "te" && !value.trim().eq_ignore_ascii_case("trailers") {
+            continue;
+        }
+        headers.push((name, value));
+    }
+    Ok(headers)
+}
+
+/// Register header helpers with Python
+pub fn register_headers(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<CookieBuilder>()?;
+    m.add_class::<SecurityHeaderPreset>()?;
+
+    // Signed cookies
+    m.add_function(wrap_pyfunction!(sign_cookie, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_cookie, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_signed_cookies, m)?)?;
+
+    // Vary header management
+    m.add_function(wrap_pyfunction!(build_vary_header, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_vary_header, m)?)?;
+    m.add_function(wrap_pyfunction!(merge_vary_headers, m)?)?;
+    m.add_function(wrap_pyfunction!(should_vary_on, m)?)?;
+
+    // HTTP dates for conditional requests
+    m.add_function(wrap_pyfunction!(parse_http_date, m)?)?;
+    m.add_function(wrap_pyfunction!(format_http_date, m)?)?;
+    m.add_function(wrap_pyfunction!(is_not_modified, m)?)?;
+
+    // HTTP/2 pseudo-headers
+    m.add_function(wrap_pyfunction!(split_http2_headers, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_http2_pseudo_headers, m)?)?;
+    m.add_function(wrap_pyfunction!(build_http2_request_headers, m)?)?;
+    Ok(())
+}
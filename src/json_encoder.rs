@@ -0,0 +1,304 @@
+use std::collections::HashSet;
+
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyBytes, PyDict, PyFloat, PyInt, PyList, PyString, PyTuple, PyType};
+use serde_json::{Map, Value, json};
+
+/// Generate a JSON Schema document for a Python type annotation
+#[pyfunction]
+fn generate_json_schema(py: Python, annotation: Bound<'_, PyAny>) -> PyResult<String> {
+    let typing = py.import("typing")?;
+    Ok(schema_for(py, &typing, &annotation)?.to_string())
+}
+
+/// Map an annotation to a schema, recursing into generic arguments
+fn schema_for(py: Python, typing: &Bound<'_, PyModule>, annotation: &Bound<'_, PyAny>) -> PyResult<Value> {
+    if annotation.is_none() || annotation.is(py.None().bind(py).get_type()) {
+        return Ok(json!({"type": "null"}));
+    }
+    if annotation.is(&typing.getattr("Any")?) {
+        return Ok(json!({}));
+    }
+
+    let origin = typing.call_method1("get_origin", (annotation,))?;
+    if origin.is_none() {
+        return scalar_schema(py, annotation);
+    }
+    let args = typing.call_method1("get_args", (annotation,))?.cast_into::<PyTuple>()?;
+
+    if is_union(py, typing, &origin)? {
+        let none_type = py.None().bind(py).get_type();
+        let variants: Vec<Bound<'_, PyAny>> = args.iter().filter(|arg| !arg.is(&none_type)).collect();
+        let nullable = variants.len() < args.len();
+        let mut schemas = variants
+            .iter()
+            .map(|arg| schema_for(py, typing, arg))
+            .collect::<PyResult<Vec<_>>>()?;
+        if nullable {
+            schemas.push(json!({"type": "null"}));
+        }
+        let keyword = if nullable && variants.len() == 1 { "oneOf" } else { "anyOf" };
+        return Ok(json!({ keyword: schemas }));
+    }
+
+    if origin.is(&typing.getattr("Literal")?) {
+        let values = args
+            .iter()
+            .map(|arg| literal_value(&arg))
+            .collect::<PyResult<Vec<_>>>()?;
+        return Ok(json!({"enum": values}));
+    }
+
+    let builtins = py.import("builtins")?;
+    if origin.is(&builtins.getattr("list")?) || origin.is(&builtins.getattr("set")?) || origin.is(&builtins.getattr("frozenset")?) {
+        let mut schema = Map::new();
+        schema.insert("type".to_string(), json!("array"));
+        if let Ok(item) = args.get_item(0) {
+            schema.insert("items".to_string(), schema_for(py, typing, &item)?);
+        }
+        if !origin.is(&builtins.getattr("list")?) {
+            schema.insert("uniqueItems".to_string(), json!(true));
+        }
+        return Ok(Value::Object(schema));
+    }
+
+    if origin.is(&builtins.getattr("tuple")?) {
+        // tuple[T, ...] is a homogeneous array; tuple[A, B] has fixed positions
+        if args.len() == 2 && args.get_item(1)?.is(py.Ellipsis()) {
+            return Ok(json!({"type": "array", "items": schema_for(py, typing, &args.get_item(0)?)?}));
+        }
+        let items = args
+            .iter()
+            .map(|arg| schema_for(py, typing, &arg))
+            .collect::<PyResult<Vec<_>>>()?;
+        let count = items.len();
+        return Ok(json!({"type": "array", "prefixItems": items, "minItems": count, "maxItems": count}));
+    }
+
+    if origin.is(&builtins.getattr("dict")?) {
+        let mut schema = Map::new();
+        schema.insert("type".to_string(), json!("object"));
+        if args.len() == 2 {
+            if !args.get_item(0)?.is(&builtins.getattr("str")?) {
+                return Err(pyo3::exceptions::PyTypeError::new_err(
+                    "JSON object keys must be str; cannot generate a schema for non-str dict keys",
+                ));
+            }
+            schema.insert("additionalProperties".to_string(), schema_for(py, typing, &args.get_item(1)?)?);
+        }
+        return Ok(Value::Object(schema));
+    }
+
+    Err(pyo3::exceptions::PyTypeError::new_err(format!(
+        "Cannot generate a JSON schema for {}",
+        annotation.repr()?
+    )))
+}
+
+/// Schema for a plain, non-generic annotation
+fn scalar_schema(py: Python, annotation: &Bound<'_, PyAny>) -> PyResult<Value> {
+    let builtins = py.import("builtins")?;
+    // bool is checked before int since bool subclasses int
+    let scalars = [
+        ("bool", json!({"type": "boolean"})),
+        ("int", json!({"type": "integer"})),
+        ("float", json!({"type": "number"})),
+        ("str", json!({"type": "string"})),
+        ("list", json!({"type": "array"})),
+        ("dict", json!({"type": "object"})),
+    ];
+    for (name, schema) in scalars {
+        if annotation.is(&builtins.getattr(name)?) {
+            return Ok(schema);
+        }
+    }
+
+    let name = match annotation.cast::<PyType>() {
+        Ok(ty) => ty.qualname()?.to_string(),
+        Err(_) => annotation.repr()?.to_string(),
+    };
+    Err(pyo3::exceptions::PyTypeError::new_err(format!(
+        "Cannot generate a JSON schema for {}",
+        name
+    )))
+}
+
+/// `typing.Union[...]`, `Optional[...]` and `X | Y` all report a union origin
+fn is_union(py: Python, typing: &Bound<'_, PyModule>, origin: &Bound<'_, PyAny>) -> PyResult<bool> {
+    if origin.is(&typing.getattr("Union")?) {
+        return Ok(true);
+    }
+    match py.import("types")?.getattr("UnionType") {
+        Ok(union_type) => Ok(origin.is(&union_type)),
+        Err(_) => Ok(false),
+    }
+}
+
+fn literal_value(value: &Bound<'_, PyAny>) -> PyResult<Value> {
+    if value.is_none() {
+        Ok(Value::Null)
+    } else if let Ok(b) = value.extract::<bool>() {
+        Ok(json!(b))
+    } else if let Ok(i) = value.extract::<i64>() {
+        Ok(json!(i))
+    } else if let Ok(s) = value.extract::<String>() {
+        Ok(json!(s))
+    } else {
+        Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "Unsupported Literal value: {}",
+            value.repr()?
+        )))
+    }
+}
+
+/// Encode a list of dicts that share one key set (e.g. query result rows) as a
+/// JSON array. Keys are read from `schema_hint` or the first row and encoded once;
+/// rows with a different key set fall back to generic dict encoding.
+#[pyfunction]
+#[pyo3(signature = (rows, schema_hint=None))]
+fn encode_rows<'py>(
+    py: Python<'py>,
+    rows: &Bound<'py, PyList>,
+    schema_hint: Option<Vec<String>>,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let key_names = match schema_hint {
+        Some(keys) => keys,
+        None => match rows.iter().next() {
+            Some(first) => row_dict(&first)?
+                .keys()
+                .iter()
+                .map(|key| Ok(key.str()?.to_string()))
+                .collect::<PyResult<_>>()?,
+            None => Vec::new(),
+        },
+    };
+    // Each key paired with its pre-encoded `"key":` prefix
+    let keys = key_names
+        .iter()
+        .map(|name| {
+            let mut prefix = serde_json::to_vec(name).map_err(json_error)?;
+            prefix.push(b':');
+            Ok((PyString::new(py, name), prefix))
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let mut out = Vec::with_capacity(rows.len() * (keys.len() * 16 + 2) + 2);
+    let mut active = HashSet::new();
+    out.push(b'[');
+    for (i, row) in rows.iter().enumerate() {
+        if i > 0 {
+            out.push(b',');
+        }
+        let row = row_dict(&row)?;
+        let values = if row.len() == keys.len() {
+            keys.iter()
+                .map(|(key, _)| row.get_item(key))
+                .collect::<PyResult<Option<Vec<_>>>>()?
+        } else {
+            None
+        };
+
+        let Some(values) = values else {
+            write_value(&mut out, row.as_any(), &mut active)?;
+            continue;
+        };
+        out.push(b'{');
+        for (j, ((_, prefix), value)) in keys.iter().zip(values).enumerate() {
+            if j > 0 {
+                out.push(b',');
+            }
+            out.extend_from_slice(prefix);
+            write_value(&mut out, &value, &mut active)?;
+        }
+        out.push(b'}');
+    }
+    out.push(b']');
+
+    Ok(PyBytes::new(py, &out))
+}
+
+/// Deepest container nesting `encode_rows` writes before giving up
+const MAX_DEPTH: usize = 256;
+
+/// Write a Python value as JSON, dispatching on its type rather than trying
+/// extractions in turn. Unknown types are written as their `str()`, like
+/// template contexts. `active` holds the ids of the containers currently being
+/// written, so a container that contains itself is rejected instead of
+/// recursing until the stack overflows.
+fn write_value(out: &mut Vec<u8>, value: &Bound<'_, PyAny>, active: &mut HashSet<usize>) -> PyResult<()> {
+    if value.is_none() {
+        out.extend_from_slice(b"null");
+    } else if let Ok(s) = value.cast::<PyString>() {
+        serde_json::to_writer(&mut *out, s.to_str()?).map_err(json_error)?;
+    } else if let Ok(b) = value.cast::<PyBool>() {
+        out.extend_from_slice(if b.is_true() { b"true" } else { b"false" });
+    } else if value.is_instance_of::<PyInt>() {
+        match value.extract::<i64>() {
+            Ok(i) => out.extend_from_slice(i.to_string().as_bytes()),
+            // Arbitrary precision ints keep all digits
+            Err(_) => out.extend_from_slice(value.str()?.to_str()?.as_bytes()),
+        }
+    } else if let Ok(f) = value.cast::<PyFloat>() {
+        match serde_json::Number::from_f64(f.value()) {
+            Some(n) => out.extend_from_slice(n.to_string().as_bytes()),
+            None => out.extend_from_slice(b"null"),
+        }
+    } else if let Ok(dict) = value.cast::<PyDict>() {
+        enter_container(value, active)?;
+        out.push(b'{');
+        for (i, (key, item)) in dict.iter().enumerate() {
+            if i > 0 {
+                out.push(b',');
+            }
+            serde_json::to_writer(&mut *out, key.str()?.to_str()?).map_err(json_error)?;
+            out.push(b':');
+            write_value(out, &item, active)?;
+        }
+        out.push(b'}');
+        active.remove(&(value.as_ptr() as usize));
+    } else if value.is_instance_of::<PyList>() || value.is_instance_of::<PyTuple>() {
+        enter_container(value, active)?;
+        out.push(b'[');
+        for (i, item) in value.try_iter()?.enumerate() {
+            if i > 0 {
+                out.push(b',');
+            }
+            write_value(out, &item?, active)?;
+        }
+        out.push(b']');
+        active.remove(&(value.as_ptr() as usize));
+    } else {
+        serde_json::to_writer(&mut *out, value.str()?.to_str()?).map_err(json_error)?;
+    }
+    Ok(())
+}
+
+fn enter_container(value: &Bound<'_, PyAny>, active: &mut HashSet<usize>) -> PyResult<()> {
+    if active.len() >= MAX_DEPTH {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Maximum nesting depth of {} exceeded",
+            MAX_DEPTH
+        )));
+    }
+    if !active.insert(value.as_ptr() as usize) {
+        return Err(pyo3::exceptions::PyValueError::new_err("Circular reference detected"));
+    }
+    Ok(())
+}
+
+fn row_dict<'a, 'py>(row: &'a Bound<'py, PyAny>) -> PyResult<&'a Bound<'py, PyDict>> {
+    row.cast::<PyDict>().map_err(|_| {
+        pyo3::exceptions::PyTypeError::new_err("encode_rows expects a list of dicts")
+    })
+}
+
+fn json_error(err: serde_json::Error) -> PyErr {
+    pyo3::exceptions::PyValueError::new_err(err.to_string())
+}
+
+/// Register JSON helpers with Python
+pub fn register_json_encoder(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(generate_json_schema, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_rows, m)?)?;
+    Ok(())
+}
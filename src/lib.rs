@@ -14,14 +14,19 @@ static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
 use pyo3::prelude::*;
 
+mod auth;
 mod background;
 mod convertors;
 mod di;
+mod graphql;
+mod json_encoder;
 mod logging;
+mod performance;
 mod proxy;
 mod routing;
 mod templates;
 mod formparsers;
+mod headers;
 mod event;
 mod responses;
 
@@ -58,6 +63,21 @@ fn _velithon(m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     // Register response handling system
     responses::register_responses(m.py(), m)?;
+
+    // Register header builders and helpers
+    headers::register_headers(m.py(), m)?;
+
+    // Register performance monitoring
+    performance::register_performance(m.py(), m)?;
+
+    // Register request signing for service-to-service authentication
+    auth::register_auth(m.py(), m)?;
+
+    // Register GraphQL helpers
+    graphql::register_graphql(m.py(), m)?;
+
+    // Register JSON schema helpers
+    json_encoder::register_json_encoder(m.py(), m)?;
     
     Ok(())
 }
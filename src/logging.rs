@@ -2,16 +2,24 @@ use chrono::{DateTime, Utc};
 use crossbeam_channel::{unbounded, Receiver, Sender};
 use flate2::write::GzEncoder;
 use flate2::Compression;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::{Method, Request, Uri};
+use hyper_util::client::legacy::{Client, connect::HttpConnector};
 use parking_lot::Mutex;
 use pyo3::prelude::*;
+use pyo3_async_runtimes::tokio::get_runtime;
 use serde::{Deserialize, Serialize};
 use serde_json;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::fs::{File, OpenOptions};
 use std::io::{BufWriter, Write};
 use std::path::Path;
 use std::sync::{Arc, OnceLock};
 use std::thread;
+use std::time::Duration;
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum LogLevel {
@@ -363,6 +371,121 @@ impl Handler for FileHandler {
     }
 }
 
+/// Buffers records for the remote shipping task; the oldest records are
+/// dropped once `max_buffer_size` is reached
+pub struct RemoteHandler {
+    level: LogLevel,
+    buffer: Arc<Mutex<VecDeque<LogRecord>>>,
+    max_buffer_size: usize,
+    batch_size: usize,
+    batch_ready: Arc<Notify>,
+}
+
+impl Handler for RemoteHandler {
+    fn handle(&self, record: &LogRecord) {
+        let level = LogLevel::from_str(&record.level);
+        if !self.is_enabled(&level) {
+            return;
+        }
+
+        let mut buffer = self.buffer.lock();
+        if buffer.len() >= self.max_buffer_size {
+            buffer.pop_front();
+        }
+        buffer.push_back(record.clone());
+        if buffer.len() >= self.batch_size {
+            self.batch_ready.notify_one();
+        }
+    }
+
+    fn set_level(&mut self, level: LogLevel) {
+        self.level = level;
+    }
+
+    fn is_enabled(&self, level: &LogLevel) -> bool {
+        level.to_int() >= self.level.to_int()
+    }
+}
+
+// Delays between retries double after each failure up to this limit
+const REMOTE_MAX_BACKOFF: Duration = Duration::from_secs(60);
+const REMOTE_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// POSTs buffered records to the remote endpoint as JSON arrays
+struct RemoteShipper {
+    url: Uri,
+    client: Client<HttpConnector, Full<Bytes>>,
+    buffer: Arc<Mutex<VecDeque<LogRecord>>>,
+    max_buffer_size: usize,
+    batch_size: usize,
+}
+
+impl RemoteShipper {
+    /// Send one batch; on failure the batch is put back at the front of the buffer
+    async fn ship_batch(&self) -> Result<usize, String> {
+        let batch: Vec<LogRecord> = {
+            let mut buffer = self.buffer.lock();
+            let count = buffer.len().min(self.batch_size);
+            buffer.drain(..count).collect()
+        };
+        if batch.is_empty() {
+            return Ok(0);
+        }
+
+        match self.post(&batch).await {
+            Ok(()) => Ok(batch.len()),
+            Err(e) => {
+                let mut buffer = self.buffer.lock();
+                for record in batch.into_iter().rev() {
+                    buffer.push_front(record);
+                }
+                while buffer.len() > self.max_buffer_size {
+                    buffer.pop_front();
+                }
+                Err(e)
+            }
+        }
+    }
+
+    async fn post(&self, batch: &[LogRecord]) -> Result<(), String> {
+        let body = serde_json::to_vec(batch).map_err(|e| e.to_string())?;
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(self.url.clone())
+            .header("content-type", "application/json")
+            .body(Full::new(Bytes::from(body)))
+            .map_err(|e| e.to_string())?;
+
+        let response = tokio::time::timeout(REMOTE_REQUEST_TIMEOUT, self.client.request(request))
+            .await
+            .map_err(|_| "request timed out".to_string())?
+            .map_err(|e| e.to_string())?;
+        if !response.status().is_success() {
+            return Err(format!("endpoint returned {}", response.status()));
+        }
+        Ok(())
+    }
+
+    /// Send batches until the buffer is empty, returning the number of records sent
+    async fn flush(&self) -> Result<usize, String> {
+        let mut sent = 0;
+        loop {
+            match self.ship_batch().await? {
+                0 => return Ok(sent),
+                count => sent += count,
+            }
+        }
+    }
+}
+
+struct RemoteLogging {
+    handler: Arc<RemoteHandler>,
+    shipper: Arc<RemoteShipper>,
+    task: JoinHandle<()>,
+}
+
+static REMOTE_LOGGING: Mutex<Option<RemoteLogging>> = Mutex::new(None);
+
 pub struct Logger {
     level: LogLevel,
     handlers: Vec<Arc<dyn Handler>>,
@@ -632,6 +755,137 @@ pub fn log_critical_with_extra(
     Ok(())
 }
 
+/// Ship log records to `url` (plain HTTP) as JSON arrays of up to `batch_size`
+/// records, every `flush_interval_ms` or as soon as a full batch is buffered.
+/// Failed batches stay buffered and are retried with exponential backoff.
+#[pyfunction]
+#[pyo3(signature = (url, batch_size=100, flush_interval_ms=1000, max_buffer_size=10000, level="INFO"))]
+pub fn add_remote_handler(
+    url: String,
+    batch_size: usize,
+    flush_interval_ms: u64,
+    max_buffer_size: usize,
+    level: &str,
+) -> PyResult<()> {
+    let uri: Uri = url
+        .parse()
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid URL '{}': {}", url, e)))?;
+    if uri.scheme_str() != Some("http") {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "Remote log handler only supports http:// URLs",
+        ));
+    }
+    if batch_size == 0 || flush_interval_ms == 0 || max_buffer_size == 0 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "batch_size, flush_interval_ms and max_buffer_size must be greater than 0",
+        ));
+    }
+
+    remove_remote_handler();
+
+    let buffer = Arc::new(Mutex::new(VecDeque::new()));
+    let batch_ready = Arc::new(Notify::new());
+    let handler = Arc::new(RemoteHandler {
+        level: LogLevel::from_str(level),
+        buffer: Arc::clone(&buffer),
+        max_buffer_size,
+        batch_size,
+        batch_ready: Arc::clone(&batch_ready),
+    });
+    let shipper = Arc::new(RemoteShipper {
+        url: uri,
+        client: Client::builder(hyper_util::rt::TokioExecutor::new()).build_http(),
+        buffer,
+        max_buffer_size,
+        batch_size,
+    });
+
+    let task_shipper = Arc::clone(&shipper);
+    let interval = Duration::from_millis(flush_interval_ms);
+    let task = get_runtime().spawn(async move {
+        let mut failures: u32 = 0;
+        loop {
+            if failures == 0 {
+                tokio::select! {
+                    _ = tokio::time::sleep(interval) => {}
+                    _ = batch_ready.notified() => {}
+                }
+            } else {
+                let backoff = interval.saturating_mul(1 << failures.min(16));
+                tokio::time::sleep(backoff.min(REMOTE_MAX_BACKOFF)).await;
+            }
+
+            match task_shipper.flush().await {
+                Ok(_) => failures = 0,
+                Err(e) => {
+                    if failures == 0 {
+                        eprintln!("Failed to ship logs to {}: {}", task_shipper.url, e);
+                    }
+                    failures = failures.saturating_add(1);
+                }
+            }
+        }
+    });
+
+    let logger_arc = get_logger();
+    let mut logger = logger_arc.lock();
+    logger.add_handler(handler.clone());
+    // The async worker holds its own copy of the handler list
+    if logger.sender.is_some() {
+        logger.start_async_processing();
+    }
+
+    *REMOTE_LOGGING.lock() = Some(RemoteLogging { handler, shipper, task });
+    Ok(())
+}
+
+/// Immediately send all buffered records to the remote endpoint, returning the count sent
+#[pyfunction]
+pub fn flush_remote(py: Python) -> PyResult<usize> {
+    let shipper = REMOTE_LOGGING
+        .lock()
+        .as_ref()
+        .map(|remote| Arc::clone(&remote.shipper))
+        .ok_or_else(|| pyo3::exceptions::PyRuntimeError::new_err("No remote log handler configured"))?;
+
+    let result = py.detach(|| match tokio::runtime::Handle::try_current() {
+        Err(_) => Ok(get_runtime().block_on(shipper.flush())),
+        // `block_on` panics on runtime threads (e.g. sync event listeners), so
+        // run the flush as a task and wait for it without holding up the worker
+        Ok(handle) if handle.runtime_flavor() != tokio::runtime::RuntimeFlavor::CurrentThread => {
+            let (tx, rx) = std::sync::mpsc::channel();
+            get_runtime().spawn(async move {
+                let _ = tx.send(shipper.flush().await);
+            });
+            tokio::task::block_in_place(|| rx.recv())
+                .map_err(|_| pyo3::exceptions::PyRuntimeError::new_err("Remote log flush was cancelled"))
+        }
+        Ok(_) => Err(pyo3::exceptions::PyRuntimeError::new_err(
+            "flush_remote cannot block inside a single-threaded runtime",
+        )),
+    })?;
+    result.map_err(|e| pyo3::exceptions::PyConnectionError::new_err(format!("Failed to ship logs: {}", e)))
+}
+
+/// Stop shipping logs remotely; records still buffered are discarded
+#[pyfunction]
+pub fn remove_remote_handler() {
+    let Some(remote) = REMOTE_LOGGING.lock().take() else {
+        return;
+    };
+    remote.task.abort();
+
+    let logger_arc = get_logger();
+    let mut logger = logger_arc.lock();
+    let remote_ptr = Arc::as_ptr(&remote.handler) as *const ();
+    logger
+        .handlers
+        .retain(|handler| Arc::as_ptr(handler) as *const () != remote_ptr);
+    if logger.sender.is_some() {
+        logger.start_async_processing();
+    }
+}
+
 #[pyfunction]
 pub fn is_enabled_for(level: String) -> PyResult<bool> {
     let log_level = LogLevel::from_str(&level);
@@ -653,5 +907,8 @@ pub fn register_logging(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(log_critical, m)?)?;
     m.add_function(wrap_pyfunction!(log_critical_with_extra, m)?)?;
     m.add_function(wrap_pyfunction!(is_enabled_for, m)?)?;
+    m.add_function(wrap_pyfunction!(add_remote_handler, m)?)?;
+    m.add_function(wrap_pyfunction!(flush_remote, m)?)?;
+    m.add_function(wrap_pyfunction!(remove_remote_handler, m)?)?;
     Ok(())
 }
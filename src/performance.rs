@@ -0,0 +1,755 @@
+use parking_lot::{Mutex, RwLock};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3_async_runtimes::tokio::get_runtime;
+use serde_json::{Value, json};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::task::JoinHandle;
+
+/// USER_HZ is fixed at 100 in the Linux userspace ABI
+const CLOCK_TICKS_PER_SEC: f64 = 100.0;
+
+#[derive(Debug, Clone, Copy)]
+struct ResourceSample {
+    cpu_pct: f64,
+    rss_mb: f64,
+    timestamp: u64, // Unix time in milliseconds
+}
+
+impl ResourceSample {
+    fn to_dict<'py>(self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("cpu_pct", self.cpu_pct)?;
+        dict.set_item("rss_mb", self.rss_mb)?;
+        dict.set_item("timestamp", self.timestamp)?;
+        Ok(dict)
+    }
+}
+
+/// Periodically samples process CPU usage and resident memory.
+/// Reads /proc/self, so sampling is only available on Linux.
+#[pyclass]
+pub struct ResourceSampler {
+    samples: Arc<Mutex<VecDeque<ResourceSample>>>,
+    max_samples: usize,
+    task: Mutex<Option<JoinHandle<()>>>,
+}
+
+#[pymethods]
+impl ResourceSampler {
+    #[new]
+    #[pyo3(signature = (max_samples=1000))]
+    fn new(max_samples: usize) -> PyResult<Self> {
+        if max_samples == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err("max_samples must be greater than 0"));
+        }
+        Ok(Self {
+            samples: Arc::new(Mutex::new(VecDeque::with_capacity(max_samples))),
+            max_samples,
+            task: Mutex::new(None),
+        })
+    }
+
+    /// Whether this platform exposes the process statistics the sampler reads
+    #[getter]
+    fn available(&self) -> bool {
+        read_cpu_seconds().is_some() && read_rss_mb().is_some()
+    }
+
+    /// Start sampling in the background, replacing any running sampler task
+    fn start(&self, interval_ms: u64) -> PyResult<()> {
+        if interval_ms == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err("interval_ms must be greater than 0"));
+        }
+        if !self.available() {
+            return Err(pyo3::exceptions::PyRuntimeError::new_err(
+                "Resource sampling needs /proc/self, which this platform does not provide",
+            ));
+        }
+
+        let samples = Arc::clone(&self.samples);
+        let max_samples = self.max_samples;
+        let handle = get_runtime().spawn(async move {
+            let mut ticker = tokio::time::interval(Duration::from_millis(interval_ms));
+            let mut previous: Option<(f64, Instant)> = None;
+            loop {
+                ticker.tick().await;
+                let now = Instant::now();
+                let cpu_secs = read_cpu_seconds();
+
+                let cpu_pct = match (previous, cpu_secs) {
+                    (Some((prev_cpu, prev_at)), Some(cpu)) => {
+                        let wall = now.duration_since(prev_at).as_secs_f64();
+                        if wall > 0.0 { (cpu - prev_cpu) / wall * 100.0 } else { 0.0 }
+                    }
+                    _ => 0.0,
+                };
+                previous = cpu_secs.map(|cpu| (cpu, now));
+
+                let sample = ResourceSample {
+                    cpu_pct,
+                    rss_mb: read_rss_mb().unwrap_or(0.0),
+                    timestamp: unix_millis(),
+                };
+
+                let mut samples = samples.lock();
+                if samples.len() >= max_samples {
+                    samples.pop_front();
+                }
+                samples.push_back(sample);
+            }
+        });
+
+        if let Some(previous) = self.task.lock().replace(handle) {
+            previous.abort();
+        }
+        Ok(())
+    }
+
+    /// Stop the background sampler task
+    fn stop(&self) {
+        if let Some(handle) = self.task.lock().take() {
+            handle.abort();
+        }
+    }
+
+    /// Whether the sampler task is running
+    fn is_running(&self) -> bool {
+        self.task.lock().as_ref().is_some_and(|handle| !handle.is_finished())
+    }
+
+    /// Most recent sample as {"cpu_pct", "rss_mb", "timestamp"}, or None before the first sample
+    fn get_latest<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyDict>>> {
+        let latest = self.samples.lock().back().copied();
+        latest.map(|sample| sample.to_dict(py)).transpose()
+    }
+
+    /// Last `n` samples, oldest first
+    fn get_history<'py>(&self, py: Python<'py>, n: usize) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        let history: Vec<ResourceSample> = {
+            let samples = self.samples.lock();
+            samples.iter().skip(samples.len().saturating_sub(n)).copied().collect()
+        };
+        history.iter().map(|sample| sample.to_dict(py)).collect()
+    }
+}
+
+impl Drop for ResourceSampler {
+    fn drop(&mut self) {
+        // The task only holds the samples, so it would otherwise outlive the sampler
+        self.stop();
+    }
+}
+
+/// Total user + system CPU time of this process in seconds
+fn read_cpu_seconds() -> Option<f64> {
+    let stat = std::fs::read_to_string("/proc/self/stat").ok()?;
+    // The command name may contain spaces, so split after its closing parenthesis.
+    // utime and stime are fields 14 and 15, i.e. 12th and 13th after the state field.
+    let fields: Vec<&str> = stat.rsplit_once(')')?.1.split_whitespace().collect();
+    let utime: f64 = fields.get(11)?.parse().ok()?;
+    let stime: f64 = fields.get(12)?.parse().ok()?;
+    Some((utime + stime) / CLOCK_TICKS_PER_SEC)
+}
+
+/// Resident set size of this process in megabytes
+fn read_rss_mb() -> Option<f64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+    let kb: f64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kb / 1024.0)
+}
+
+fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Identifies a span in W3C Trace Context form
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct SpanContext {
+    #[pyo3(get)]
+    trace_id: String,
+    #[pyo3(get)]
+    span_id: String,
+    #[pyo3(get)]
+    parent_span_id: Option<String>,
+    #[pyo3(get)]
+    name: String,
+    start_time_nanos: u64,
+}
+
+#[pymethods]
+impl SpanContext {
+    /// `traceparent` header value for propagating this span downstream
+    fn traceparent(&self) -> String {
+        format!("00-{}-{}-01", self.trace_id, self.span_id)
+    }
+
+    /// `tracestate` header value; Velithon adds no vendor entries
+    fn tracestate(&self) -> String {
+        String::new()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("SpanContext(name='{}', traceparent='{}')", self.name, self.traceparent())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CompletedSpan {
+    context: SpanContext,
+    end_time_nanos: u64,
+    status: &'static str,
+    attributes: HashMap<String, String>,
+}
+
+impl CompletedSpan {
+    fn to_otlp(&self) -> Value {
+        let status_code = match self.status {
+            "ok" => 1,
+            "error" => 2,
+            _ => 0,
+        };
+        let mut attributes: Vec<(&String, &String)> = self.attributes.iter().collect();
+        attributes.sort();
+
+        json!({
+            "traceId": self.context.trace_id,
+            "spanId": self.context.span_id,
+            "parentSpanId": self.context.parent_span_id.clone().unwrap_or_default(),
+            "name": self.context.name,
+            "startTimeUnixNano": self.context.start_time_nanos.to_string(),
+            "endTimeUnixNano": self.end_time_nanos.to_string(),
+            "status": { "code": status_code },
+            "attributes": attributes
+                .into_iter()
+                .map(|(key, value)| json!({ "key": key, "value": { "stringValue": value } }))
+                .collect::<Vec<_>>(),
+        })
+    }
+}
+
+/// Creates W3C Trace Context compatible spans and keeps completed ones for export.
+/// Only the most recent `max_spans` completed spans are retained.
+#[pyclass]
+pub struct OTelTracer {
+    completed: Mutex<VecDeque<CompletedSpan>>,
+    max_spans: usize,
+}
+
+#[pymethods]
+impl OTelTracer {
+    #[new]
+    #[pyo3(signature = (max_spans=10000))]
+    fn new(max_spans: usize) -> PyResult<Self> {
+        if max_spans == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err("max_spans must be greater than 0"));
+        }
+        Ok(Self {
+            completed: Mutex::new(VecDeque::new()),
+            max_spans,
+        })
+    }
+
+    /// Start a span, continuing the parent trace when one is given
+    #[pyo3(signature = (name, parent_trace_id=None, parent_span_id=None))]
+    fn start_span(
+        &self,
+        name: &str,
+        parent_trace_id: Option<&str>,
+        parent_span_id: Option<&str>,
+    ) -> PyResult<SpanContext> {
+        if let Some(trace_id) = parent_trace_id
+            && !is_valid_trace_hex(trace_id, 32)
+        {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Invalid parent trace id: {}",
+                trace_id
+            )));
+        }
+        if let Some(span_id) = parent_span_id
+            && !is_valid_trace_hex(span_id, 16)
+        {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Invalid parent span id: {}",
+                span_id
+            )));
+        }
+
+        let trace_id = match parent_trace_id {
+            Some(trace_id) => trace_id.to_string(),
+            None => uuid::Uuid::new_v4().simple().to_string(),
+        };
+        let mut span_id = uuid::Uuid::new_v4().simple().to_string();
+        span_id.truncate(16);
+
+        Ok(SpanContext {
+            trace_id,
+            span_id,
+            parent_span_id: parent_span_id.map(str::to_string),
+            name: name.to_string(),
+            start_time_nanos: unix_nanos(),
+        })
+    }
+
+    /// Start a span continuing the trace in an incoming `traceparent` header
+    fn start_span_from_traceparent(&self, name: &str, traceparent: &str) -> PyResult<SpanContext> {
+        let (trace_id, span_id) = parse_traceparent(traceparent).ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(format!("Invalid traceparent: {}", traceparent))
+        })?;
+        self.start_span(name, Some(trace_id), Some(span_id))
+    }
+
+    /// Record a finished span with status "ok", "error" or "unset"
+    #[pyo3(signature = (ctx, status="ok", attributes=None))]
+    fn end_span(
+        &self,
+        ctx: &SpanContext,
+        status: &str,
+        attributes: Option<HashMap<String, String>>,
+    ) -> PyResult<()> {
+        let status = match status.to_ascii_lowercase().as_str() {
+            "ok" => "ok",
+            "error" => "error",
+            "unset" => "unset",
+            _ => {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "Invalid span status: {}. Expected 'ok', 'error' or 'unset'",
+                    status
+                )));
+            }
+        };
+
+        let span = CompletedSpan {
+            context: ctx.clone(),
+            end_time_nanos: unix_nanos(),
+            status,
+            attributes: attributes.unwrap_or_default(),
+        };
+
+        let mut completed = self.completed.lock();
+        if completed.len() >= self.max_spans {
+            completed.pop_front();
+        }
+        completed.push_back(span);
+        Ok(())
+    }
+
+    /// All completed spans as an OTLP JSON array
+    fn export_spans_json(&self) -> String {
+        let spans: Vec<Value> = self.completed.lock().iter().map(CompletedSpan::to_otlp).collect();
+        Value::Array(spans).to_string()
+    }
+
+    /// Number of completed spans currently held
+    fn span_count(&self) -> usize {
+        self.completed.lock().len()
+    }
+
+    /// Drop all completed spans
+    fn clear(&self) {
+        self.completed.lock().clear();
+    }
+}
+
+/// Trace and parent span ids of a `traceparent` header. Versions after 00 may
+/// append fields, but version ff is invalid.
+fn parse_traceparent(value: &str) -> Option<(&str, &str)> {
+    let mut fields = value.trim().split('-');
+    let version = fields.next()?;
+    let trace_id = fields.next()?;
+    let span_id = fields.next()?;
+    let flags = fields.next()?;
+
+    let is_hex = |field: &str, len: usize| {
+        field.len() == len && field.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+    };
+    if !is_hex(version, 2) || version == "ff" || !is_hex(flags, 2) {
+        return None;
+    }
+    if version == "00" && fields.next().is_some() {
+        return None;
+    }
+    (is_valid_trace_hex(trace_id, 32) && is_valid_trace_hex(span_id, 16)).then_some((trace_id, span_id))
+}
+
+/// Lowercase hex of the given length that is not all zeros, as W3C Trace Context requires
+fn is_valid_trace_hex(value: &str, len: usize) -> bool {
+    value.len() == len
+        && value.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+        && value.bytes().any(|b| b != b'0')
+}
+
+fn unix_nanos() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0)
+}
+
+/// Where a throughput counter keeps its events
+enum ThroughputWindow {
+    // Every event timestamp within the window; exact, but serialized on a mutex
+    Exact(Mutex<VecDeque<Instant>>),
+    // Per-second counts in a ring of `window_secs` buckets, each tagged with the
+    // second it counts; lock-free, but concurrent resets may lose a few events
+    Buckets { counts: Vec<AtomicU64>, seconds: Vec<AtomicU64> },
+}
+
+/// Request rate over a sliding window of `window_secs` seconds
+#[pyclass]
+pub struct ThroughputCounter {
+    window: ThroughputWindow,
+    window_secs: u64,
+    started: Instant,
+    // Highest observed rate, stored as f64 bits (ordering matches for non-negative values)
+    peak_rate: AtomicU64,
+}
+
+#[pymethods]
+impl ThroughputCounter {
+    #[new]
+    #[pyo3(signature = (window_secs=60, lock_free=false))]
+    fn new(window_secs: u64, lock_free: bool) -> PyResult<Self> {
+        if window_secs == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err("window_secs must be greater than 0"));
+        }
+        let window = if lock_free {
+            ThroughputWindow::Buckets {
+                counts: (0..window_secs).map(|_| AtomicU64::new(0)).collect(),
+                seconds: (0..window_secs).map(|_| AtomicU64::new(u64::MAX)).collect(),
+            }
+        } else {
+            ThroughputWindow::Exact(Mutex::new(VecDeque::new()))
+        };
+        Ok(Self {
+            window,
+            window_secs,
+            started: Instant::now(),
+            peak_rate: AtomicU64::new(0f64.to_bits()),
+        })
+    }
+
+    /// Record one event at the current time
+    fn record(&self) {
+        let rate = match &self.window {
+            ThroughputWindow::Exact(events) => {
+                let now = Instant::now();
+                let mut events = events.lock();
+                events.push_back(now);
+                self.prune(&mut events, now);
+                events.len() as f64 / self.window_secs as f64
+            }
+            ThroughputWindow::Buckets { counts, seconds } => {
+                let second = self.started.elapsed().as_secs();
+                let slot = (second % self.window_secs) as usize;
+                let tagged = seconds[slot].load(Ordering::Acquire);
+                if tagged != second
+                    && seconds[slot]
+                        .compare_exchange(tagged, second, Ordering::AcqRel, Ordering::Acquire)
+                        .is_ok()
+                {
+                    counts[slot].store(0, Ordering::Release);
+                }
+                counts[slot].fetch_add(1, Ordering::AcqRel);
+                self.bucket_rate(counts, seconds, second)
+            }
+        };
+        self.peak_rate.fetch_max(rate.to_bits(), Ordering::AcqRel);
+    }
+
+    /// Events per second over the current window
+    fn get_rate(&self) -> f64 {
+        match &self.window {
+            ThroughputWindow::Exact(events) => {
+                let mut events = events.lock();
+                self.prune(&mut events, Instant::now());
+                events.len() as f64 / self.window_secs as f64
+            }
+            ThroughputWindow::Buckets { counts, seconds } => {
+                self.bucket_rate(counts, seconds, self.started.elapsed().as_secs())
+            }
+        }
+    }
+
+    /// Highest rate observed since creation or the last reset
+    fn get_peak_rate(&self) -> f64 {
+        f64::from_bits(self.peak_rate.load(Ordering::Acquire))
+    }
+
+    /// Forget all recorded events and the peak rate
+    fn reset(&self) {
+        match &self.window {
+            ThroughputWindow::Exact(events) => events.lock().clear(),
+            ThroughputWindow::Buckets { counts, seconds } => {
+                for (count, second) in counts.iter().zip(seconds) {
+                    second.store(u64::MAX, Ordering::Release);
+                    count.store(0, Ordering::Release);
+                }
+            }
+        }
+        self.peak_rate.store(0f64.to_bits(), Ordering::Release);
+    }
+
+    /// Whether this counter uses lock-free per-second buckets
+    fn is_lock_free(&self) -> bool {
+        matches!(self.window, ThroughputWindow::Buckets { .. })
+    }
+}
+
+impl ThroughputCounter {
+    fn prune(&self, events: &mut VecDeque<Instant>, now: Instant) {
+        let window = Duration::from_secs(self.window_secs);
+        while events.front().is_some_and(|&at| now.duration_since(at) >= window) {
+            events.pop_front();
+        }
+    }
+
+    fn bucket_rate(&self, counts: &[AtomicU64], seconds: &[AtomicU64], now_second: u64) -> f64 {
+        let total: u64 = counts
+            .iter()
+            .zip(seconds)
+            .filter(|(_, second)| {
+                let second = second.load(Ordering::Acquire);
+                second <= now_second && now_second - second < self.window_secs
+            })
+            .map(|(count, _)| count.load(Ordering::Acquire))
+            .sum();
+        total as f64 / self.window_secs as f64
+    }
+}
+
+/// Counts calls per label to find the most frequently executed code paths
+#[pyclass]
+pub struct HotPathDetector {
+    // Existing labels only need the read lock, so concurrent records don't serialize
+    counts: RwLock<HashMap<String, AtomicU64>>,
+}
+
+#[pymethods]
+impl HotPathDetector {
+    #[new]
+    fn new() -> Self {
+        Self {
+            counts: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Count one call for a label
+    fn record(&self, label: &str) {
+        if let Some(count) = self.counts.read().get(label) {
+            count.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+        self.counts
+            .write()
+            .entry(label.to_string())
+            .or_insert_with(|| AtomicU64::new(0))
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// The `top_n` labels by call count, highest first
+    fn get_hot_paths(&self, top_n: usize) -> Vec<(String, u64)> {
+        let mut paths = self.snapshot(0);
+        paths.truncate(top_n);
+        paths
+    }
+
+    /// Labels called at least `min_calls` times, highest first
+    fn get_threshold_paths(&self, min_calls: u64) -> Vec<(String, u64)> {
+        self.snapshot(min_calls)
+    }
+
+    /// Forget one label, or all labels
+    #[pyo3(signature = (label=None))]
+    fn reset(&self, label: Option<&str>) {
+        let mut counts = self.counts.write();
+        match label {
+            Some(label) => {
+                counts.remove(label);
+            }
+            None => counts.clear(),
+        }
+    }
+
+    /// Multiply every count by `factor` (0.0 to 1.0) so old traffic fades over time
+    fn decay(&self, factor: f64) -> PyResult<()> {
+        if !(0.0..=1.0).contains(&factor) {
+            return Err(pyo3::exceptions::PyValueError::new_err("factor must be between 0.0 and 1.0"));
+        }
+        for count in self.counts.read().values() {
+            let _ = count.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |value| {
+                Some((value as f64 * factor) as u64)
+            });
+        }
+        Ok(())
+    }
+}
+
+impl HotPathDetector {
+    fn snapshot(&self, min_calls: u64) -> Vec<(String, u64)> {
+        let mut paths: Vec<(String, u64)> = self
+            .counts
+            .read()
+            .iter()
+            .map(|(label, count)| (label.clone(), count.load(Ordering::Relaxed)))
+            .filter(|&(_, count)| count >= min_calls)
+            .collect();
+        paths.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        paths
+    }
+}
+
+#[derive(Debug, Clone)]
+struct StackFrame {
+    function: String,
+    filename: String,
+    line: u32,
+}
+
+/// Samples the Python call stack of every thread at a fixed interval and exports
+/// the samples in folded format for `flamegraph.pl` or `inferno`.
+/// Only the most recent `max_samples` stacks are retained.
+#[pyclass]
+pub struct SamplingProfiler {
+    // Each stack is ordered from the outermost frame to the innermost
+    samples: Arc<Mutex<VecDeque<Vec<StackFrame>>>>,
+    task: Mutex<Option<JoinHandle<()>>>,
+}
+
+#[pymethods]
+impl SamplingProfiler {
+    #[new]
+    fn new() -> Self {
+        Self {
+            samples: Arc::new(Mutex::new(VecDeque::new())),
+            task: Mutex::new(None),
+        }
+    }
+
+    /// Start sampling in the background, replacing any running profiler task
+    #[pyo3(signature = (interval_ms=10, max_samples=10000))]
+    fn start(&self, interval_ms: u64, max_samples: usize) -> PyResult<()> {
+        if interval_ms == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err("interval_ms must be greater than 0"));
+        }
+        if max_samples == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err("max_samples must be greater than 0"));
+        }
+
+        let samples = Arc::clone(&self.samples);
+        let handle = get_runtime().spawn(async move {
+            let mut ticker = tokio::time::interval(Duration::from_millis(interval_ms));
+            loop {
+                ticker.tick().await;
+                // Stack capture needs the GIL, so wait for it off the async workers
+                let stacks = tokio::task::spawn_blocking(|| Python::attach(capture_stacks)).await;
+                let Ok(Ok(stacks)) = stacks else {
+                    continue;
+                };
+
+                let mut samples = samples.lock();
+                for stack in stacks {
+                    if samples.len() >= max_samples {
+                        samples.pop_front();
+                    }
+                    samples.push_back(stack);
+                }
+            }
+        });
+
+        if let Some(previous) = self.task.lock().replace(handle) {
+            previous.abort();
+        }
+        Ok(())
+    }
+
+    /// Stop sampling and return the number of samples collected
+    fn stop(&self) -> usize {
+        if let Some(handle) = self.task.lock().take() {
+            handle.abort();
+        }
+        self.samples.lock().len()
+    }
+
+    /// Whether the profiler task is running
+    fn is_running(&self) -> bool {
+        self.task.lock().as_ref().is_some_and(|handle| !handle.is_finished())
+    }
+
+    /// Discard all collected samples
+    fn clear(&self) {
+        self.samples.lock().clear();
+    }
+
+    /// Samples as `frame;frame;frame count` lines, outermost frame first
+    fn export_folded_stacks(&self) -> String {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for stack in self.samples.lock().iter() {
+            let folded = stack
+                .iter()
+                .map(|frame| format!("{} ({}:{})", frame.function, frame.filename, frame.line))
+                .collect::<Vec<_>>()
+                .join(";");
+            *counts.entry(folded).or_insert(0) += 1;
+        }
+
+        let mut counts: Vec<(String, usize)> = counts.into_iter().collect();
+        counts.sort();
+        counts
+            .into_iter()
+            .map(|(stack, count)| format!("{} {}\n", stack, count))
+            .collect()
+    }
+}
+
+impl Drop for SamplingProfiler {
+    fn drop(&mut self) {
+        // Otherwise the task keeps taking the GIL to sample after the profiler is gone
+        if let Some(handle) = self.task.get_mut().take() {
+            handle.abort();
+        }
+    }
+}
+
+/// Current Python stack of every thread that is executing Python code
+fn capture_stacks(py: Python) -> PyResult<Vec<Vec<StackFrame>>> {
+    let frames = py.import("sys")?.call_method0("_current_frames")?.cast_into::<PyDict>()?;
+    let mut stacks = Vec::with_capacity(frames.len());
+    for (_, frame) in frames.iter() {
+        let mut stack = Vec::new();
+        let mut frame = frame;
+        while !frame.is_none() {
+            let code = frame.getattr("f_code")?;
+            stack.push(StackFrame {
+                function: code.getattr("co_name")?.extract()?,
+                filename: code.getattr("co_filename")?.extract()?,
+                line: frame.getattr("f_lineno")?.extract::<Option<u32>>()?.unwrap_or(0),
+            });
+            frame = frame.getattr("f_back")?;
+        }
+        if !stack.is_empty() {
+            stack.reverse();
+            stacks.push(stack);
+        }
+    }
+    Ok(stacks)
+}
+
+/// Register performance monitoring classes with Python
+pub fn register_performance(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<ResourceSampler>()?;
+    m.add_class::<SpanContext>()?;
+    m.add_class::<OTelTracer>()?;
+    m.add_class::<ThroughputCounter>()?;
+    m.add_class::<HotPathDetector>()?;
+    m.add_class::<SamplingProfiler>()?;
+    Ok(())
+}
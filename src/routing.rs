@@ -1,8 +1,59 @@
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyString};
 use regex::Regex;
 use ahash::AHashMap;
-use parking_lot::Mutex as ParkingLotMutex;
+use parking_lot::{Mutex as ParkingLotMutex, RwLock};
+use percent_encoding::{AsciiSet, CONTROLS, utf8_percent_encode};
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+
+use crate::convertors::{default_convertor_types, find_path_params, resolve_convertor};
+
+/// Characters percent-encoded when a parameter value is placed in a path
+const PATH_ENCODE_SET: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+// Compiled route regexes shared by every optimizer in the process, keyed by pattern
+static COMPILED_REGEX_POOL: OnceLock<RwLock<HashMap<String, Arc<Regex>>>> = OnceLock::new();
+
+/// Get a compiled regex from the shared pool, compiling and pooling it on first use
+fn pooled_regex(pattern: &str) -> PyResult<Arc<Regex>> {
+    let pool = COMPILED_REGEX_POOL.get_or_init(|| RwLock::new(HashMap::new()));
+    if let Some(regex) = pool.read().get(pattern) {
+        return Ok(Arc::clone(regex));
+    }
+
+    let regex = Arc::new(
+        Regex::new(pattern)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid regex: {}", e)))?,
+    );
+    Ok(Arc::clone(
+        pool.write().entry(pattern.to_string()).or_insert(regex),
+    ))
+}
+
+/// Number of compiled regexes in the shared pool
+#[pyfunction]
+fn get_regex_pool_size() -> usize {
+    COMPILED_REGEX_POOL.get().map_or(0, |pool| pool.read().len())
+}
+
+/// Drop all pooled regexes; optimizers keep the ones they already hold
+#[pyfunction]
+fn clear_regex_pool() {
+    if let Some(pool) = COMPILED_REGEX_POOL.get() {
+        pool.write().clear();
+    }
+}
 
 /// Match result for route matching
 #[pyclass]
@@ -38,7 +89,7 @@ impl Match {
 /// Fast route matching with path parameter extraction
 #[pyclass(name = "_RouteOptimizer")]
 pub struct RouteOptimizer {
-    path_regex: Regex,
+    path_regex: Arc<Regex>,
     param_convertors: Py<PyDict>,
     methods: Option<AHashMap<String, ()>>,
     path_cache: ParkingLotMutex<AHashMap<String, (Match, Option<AHashMap<String, Py<PyAny>>>)>>,
@@ -59,8 +110,7 @@ impl RouteOptimizer {
         methods: Option<Vec<String>>,
         max_cache_size: usize,
     ) -> PyResult<Self> {
-        let regex = Regex::new(path_regex)
-            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid regex: {}", e)))?;
+        let regex = pooled_regex(path_regex)?;
         
         let methods_map = methods.map(|m| {
             let mut map = AHashMap::new();
@@ -226,6 +276,9 @@ impl RouteOptimizer {
     }
 }
 
+// (regex, route_index, methods, convertors)
+type RegexRoute = (Arc<Regex>, usize, Vec<String>, Py<PyDict>);
+
 /// High-performance unified router with consolidated route matching
 #[pyclass(name = "_UnifiedRouteOptimizer")]
 pub struct UnifiedRouteOptimizer {
@@ -233,11 +286,100 @@ pub struct UnifiedRouteOptimizer {
     exact_routes: AHashMap<String, (usize, Vec<String>)>, // path -> (route_index, allowed_methods)
     
     // Parameterized routes with pre-compiled regexes
-    regex_routes: Vec<(Regex, usize, Vec<String>, Py<PyDict>)>, // (regex, route_index, methods, convertors)
+    regex_routes: Vec<RegexRoute>,
+
+    // Positions in regex_routes grouped by literal path prefix
+    prefix_index: StaticPrefixIndex,
     
     // Unified cache for all route lookups
     unified_cache: AHashMap<String, CacheEntry>,
     max_cache_size: usize,
+
+    // Run normalize_path on incoming paths before matching
+    normalize_paths: bool,
+}
+
+/// Groups regex routes by the literal text their pattern must start with, so a
+/// lookup only evaluates routes whose prefix the path actually begins with.
+#[derive(Debug, Default)]
+struct StaticPrefixIndex {
+    groups: AHashMap<String, Vec<usize>>, // prefix -> positions in registration order
+    prefix_lengths: Vec<usize>,           // distinct prefix lengths, ascending
+}
+
+impl StaticPrefixIndex {
+    fn add(&mut self, path_regex: &str, position: usize) {
+        let prefix = literal_regex_prefix(path_regex);
+        if let Err(at) = self.prefix_lengths.binary_search(&prefix.len()) {
+            self.prefix_lengths.insert(at, prefix.len());
+        }
+        self.groups.entry(prefix).or_default().push(position);
+    }
+
+    /// Positions of all routes whose prefix the path starts with, in registration order
+    fn candidates(&self, path: &str) -> Vec<usize> {
+        let mut positions: Vec<usize> = self
+            .prefix_lengths
+            .iter()
+            .take_while(|&&len| len <= path.len())
+            .filter(|&&len| path.is_char_boundary(len))
+            .filter_map(|&len| self.groups.get(&path[..len]))
+            .flatten()
+            .copied()
+            .collect();
+        positions.sort_unstable();
+        positions
+    }
+
+    fn clear(&mut self) {
+        self.groups.clear();
+        self.prefix_lengths.clear();
+    }
+}
+
+/// Literal text every match of an anchored pattern starts with.
+/// Returns an empty prefix for unanchored patterns or top-level alternation.
+fn literal_regex_prefix(pattern: &str) -> String {
+    let Some(body) = pattern.strip_prefix('^') else {
+        return String::new();
+    };
+
+    // `a|b` at the top level means no single prefix is required
+    let mut depth = 0usize;
+    let mut in_class = false;
+    let mut chars = body.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => {
+                chars.next();
+            }
+            '[' if !in_class => in_class = true,
+            ']' if in_class => in_class = false,
+            '(' if !in_class => depth += 1,
+            ')' if !in_class => depth = depth.saturating_sub(1),
+            '|' if !in_class && depth == 0 => return String::new(),
+            _ => {}
+        }
+    }
+
+    let mut prefix = String::new();
+    let mut chars = body.chars().peekable();
+    while let Some(ch) = chars.next() {
+        let literal = match ch {
+            '\\' => match chars.next() {
+                Some(escaped) if escaped.is_ascii_punctuation() => escaped,
+                _ => break, // class escapes such as \d
+            },
+            '.' | '^' | '$' | '(' | ')' | '[' | ']' | '|' | '*' | '+' | '?' | '{' | '}' => break,
+            ch => ch,
+        };
+        // A quantified character is not required
+        if matches!(chars.peek(), Some('*' | '?' | '{')) {
+            break;
+        }
+        prefix.push(literal);
+    }
+    prefix
 }
 
 #[derive(Debug)]
@@ -250,13 +392,15 @@ struct CacheEntry {
 #[pymethods]
 impl UnifiedRouteOptimizer {
     #[new]
-    #[pyo3(signature = (max_cache_size=2048))]
-    fn new(max_cache_size: usize) -> Self {
+    #[pyo3(signature = (max_cache_size=2048, normalize_paths=false))]
+    fn new(max_cache_size: usize, normalize_paths: bool) -> Self {
         UnifiedRouteOptimizer {
             exact_routes: AHashMap::new(),
             regex_routes: Vec::new(),
+            prefix_index: StaticPrefixIndex::default(),
             unified_cache: AHashMap::new(),
             max_cache_size,
+            normalize_paths,
         }
     }
 
@@ -285,8 +429,7 @@ impl UnifiedRouteOptimizer {
         methods: Vec<String>,
         param_convertors: Py<PyDict>
     ) -> PyResult<()> {
-        let regex = Regex::new(path_regex)
-            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid regex: {}", e)))?;
+        let regex = pooled_regex(path_regex)?;
         
         let normalized_methods: Vec<String> = methods.iter()
             .map(|m| m.to_uppercase())
@@ -298,6 +441,7 @@ impl UnifiedRouteOptimizer {
             final_methods.push("HEAD".to_string());
         }
         
+        self.prefix_index.add(path_regex, self.regex_routes.len());
         self.regex_routes.push((regex, route_index, final_methods, param_convertors));
         Ok(())
     }
@@ -305,6 +449,14 @@ impl UnifiedRouteOptimizer {
     /// Unified route matching with single cache lookup
     #[pyo3(signature = (path, method))]
     fn match_route(&mut self, py: Python, path: &str, method: &str) -> PyResult<(isize, Match, Option<Py<PyDict>>)> {
+        let normalized;
+        let path = if self.normalize_paths {
+            normalized = normalize_path(path, true);
+            normalized.as_str()
+        } else {
+            path
+        };
+
         let cache_key = format!("{}:{}", path, method.to_uppercase());
         
         // Check unified cache first
@@ -337,9 +489,10 @@ impl UnifiedRouteOptimizer {
             return Ok((route_index as isize, match_type, None));
         }
 
-        // Check regex routes
+        // Check regex routes, limited to those whose literal prefix the path starts with
         let mut match_result = None;
-        for (regex, route_index, allowed_methods, param_convertors) in &self.regex_routes {
+        for position in self.prefix_index.candidates(path) {
+            let (regex, route_index, allowed_methods, param_convertors) = &self.regex_routes[position];
             if let Some(captures) = regex.captures(path) {
                 let match_type = if allowed_methods.contains(&method_upper) {
                     Match::Full
@@ -432,10 +585,22 @@ impl UnifiedRouteOptimizer {
         )
     }
 
+    /// Get prefix index statistics
+    fn get_index_stats<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let stats = PyDict::new(py);
+        stats.set_item("prefix_groups", self.prefix_index.groups.len())?;
+        stats.set_item(
+            "largest_group",
+            self.prefix_index.groups.values().map(Vec::len).max().unwrap_or(0),
+        )?;
+        Ok(stats)
+    }
+
     /// Clear all caches and routes
     fn clear_all(&mut self) {
         self.exact_routes.clear();
         self.regex_routes.clear();
+        self.prefix_index.clear();
         self.unified_cache.clear();
     }
 
@@ -445,10 +610,99 @@ impl UnifiedRouteOptimizer {
     }
 }
 
+/// Normalize a request path: collapse repeated slashes, resolve `.` and `..`
+/// segments without climbing above the root, and optionally drop a trailing slash
+#[pyfunction]
+#[pyo3(signature = (path, strip_trailing_slash=true))]
+pub fn normalize_path(path: &str, strip_trailing_slash: bool) -> String {
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+
+    let mut normalized = String::with_capacity(path.len() + 1);
+    for segment in &segments {
+        normalized.push('/');
+        normalized.push_str(segment);
+    }
+
+    let is_directory = path.ends_with('/') || path.ends_with("/.") || path.ends_with("/..");
+    if normalized.is_empty() || (is_directory && !strip_trailing_slash) {
+        normalized.push('/');
+    }
+    normalized
+}
+
+/// Build a URL from a route pattern, replacing `{name}` and `{name:type}`
+/// placeholders with percent-encoded values from `params`. Each value is
+/// validated by its convertor's `to_string`; `convertor_types` defaults to the
+/// built-in convertors.
+#[pyfunction]
+#[pyo3(signature = (pattern, params, convertor_types=None))]
+pub fn build_url(
+    py: Python,
+    pattern: &str,
+    params: &Bound<'_, PyDict>,
+    convertor_types: Option<Bound<'_, PyDict>>,
+) -> PyResult<String> {
+    let convertor_types = match convertor_types {
+        Some(convertor_types) => convertor_types,
+        None => default_convertor_types(py)?,
+    };
+
+    let mut url = String::with_capacity(pattern.len());
+    let mut idx = 0;
+    for param in find_path_params(pattern) {
+        url.push_str(&pattern[idx..param.start]);
+
+        let value = params.get_item(param.name)?.ok_or_else(|| {
+            pyo3::exceptions::PyKeyError::new_err(format!("Missing path parameter: {}", param.name))
+        })?;
+        let convertor = resolve_convertor(py, param.spec, &convertor_types)?;
+        let rendered = param_to_string(&convertor, &value).map_err(|err| {
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "Invalid value for path parameter '{}': {}",
+                param.name,
+                err.value(py)
+            ))
+        })?;
+        // '/' is left as is: the str convertor already rejects it, and a custom
+        // regex that accepts it would not match an encoded `%2F`
+        url.extend(utf8_percent_encode(&rendered, PATH_ENCODE_SET));
+        idx = param.end;
+    }
+    url.push_str(&pattern[idx..]);
+    Ok(url)
+}
+
+/// `convertor.to_string(value)`, accepting string values for typed convertors
+/// (parsed with `convert` first) and non-string values for string convertors
+fn param_to_string(convertor: &Bound<'_, PyAny>, value: &Bound<'_, PyAny>) -> PyResult<String> {
+    let py = convertor.py();
+    match convertor.call_method1("to_string", (value,)) {
+        Ok(rendered) => rendered.extract(),
+        Err(err) if err.is_instance_of::<pyo3::exceptions::PyTypeError>(py) => {
+            let value = if value.is_instance_of::<PyString>() {
+                convertor.call_method1("convert", (value,))?
+            } else {
+                value.str()?.into_any()
+            };
+            convertor.call_method1("to_string", (value,))?.extract()
+        }
+        Err(err) => Err(err),
+    }
+}
+
 /// High-performance route pattern matcher
 #[pyclass(name = "_RoutePatternMatcher")]
 pub struct RoutePatternMatcher {
-    patterns: Vec<(Regex, String, Py<PyDict>)>, // (regex, path_format, convertors)
+    patterns: Vec<(Arc<Regex>, String, Py<PyDict>)>, // (regex, path_format, convertors)
     exact_paths: AHashMap<String, usize>, // exact path -> pattern index
 }
 
@@ -471,8 +725,7 @@ impl RoutePatternMatcher {
         param_convertors: Py<PyDict>,
         is_exact_path: bool,
     ) -> PyResult<usize> {
-        let regex = Regex::new(path_regex)
-            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid regex: {}", e)))?;
+        let regex = pooled_regex(path_regex)?;
         
         let index = self.patterns.len();
         self.patterns.push((regex, path_format, param_convertors));
@@ -546,6 +799,12 @@ pub fn register_routing(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<RouteOptimizer>()?;
     m.add_class::<UnifiedRouteOptimizer>()?;
     m.add_class::<RoutePatternMatcher>()?;
+
+    // Register path helpers
+    m.add_function(wrap_pyfunction!(normalize_path, m)?)?;
+    m.add_function(wrap_pyfunction!(build_url, m)?)?;
+    m.add_function(wrap_pyfunction!(get_regex_pool_size, m)?)?;
+    m.add_function(wrap_pyfunction!(clear_regex_pool, m)?)?;
     
     Ok(())
 }
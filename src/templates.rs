@@ -80,7 +80,7 @@ impl TemplateEngine {
 
         // Convert Python context to JSON
         let json_context = match context {
-            Some(ctx) => self.python_dict_to_json(ctx)?,
+            Some(ctx) => Self::python_dict_to_json(ctx)?,
             None => Value::Object(Map::new()),
         };
 
@@ -234,12 +234,12 @@ impl TemplateEngine {
     }
 
     /// Convert Python dictionary to JSON Value
-    fn python_dict_to_json(&self, py_dict: &Bound<'_, PyDict>) -> PyResult<Value> {
+    pub(crate) fn python_dict_to_json(py_dict: &Bound<'_, PyDict>) -> PyResult<Value> {
         let mut map = Map::new();
         
         for (key, value) in py_dict.iter() {
             let key_str = key.str()?.to_string();
-            let json_value = self.python_to_json_value(value)?;
+            let json_value = Self::python_to_json_value(value)?;
             map.insert(key_str, json_value);
         }
         
@@ -247,7 +247,7 @@ impl TemplateEngine {
     }
 
     /// Convert Python value to JSON Value
-    fn python_to_json_value(&self, py_value: Bound<'_, PyAny>) -> PyResult<Value> {
+    fn python_to_json_value(py_value: Bound<'_, PyAny>) -> PyResult<Value> {
         if py_value.is_none() {
             Ok(Value::Null)
         } else if let Ok(b) = py_value.extract::<bool>() {
@@ -265,11 +265,17 @@ impl TemplateEngine {
         } else if let Ok(list) = py_value.cast::<pyo3::types::PyList>() {
             let mut arr = Vec::new();
             for item in list.iter() {
-                arr.push(self.python_to_json_value(item)?);
+                arr.push(Self::python_to_json_value(item)?);
+            }
+            Ok(Value::Array(arr))
+        } else if let Ok(tuple) = py_value.cast::<pyo3::types::PyTuple>() {
+            let mut arr = Vec::new();
+            for item in tuple.iter() {
+                arr.push(Self::python_to_json_value(item)?);
             }
             Ok(Value::Array(arr))
         } else if let Ok(dict) = py_value.cast::<PyDict>() {
-            self.python_dict_to_json(dict)
+            Self::python_dict_to_json(dict)
         } else {
             // Fallback: convert to string
             Ok(Value::String(py_value.str()?.to_string()))
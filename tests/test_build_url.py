@@ -0,0 +1,86 @@
+"""Tests for building URLs from route patterns."""
+
+import re
+import uuid
+
+import pytest
+
+from velithon._velithon import build_url, compile_path
+from velithon.convertors import CONVERTOR_TYPES
+
+USER_ID = '12345678-1234-5678-1234-567812345678'
+
+
+@pytest.mark.parametrize(
+    ('pattern', 'params', 'expected'),
+    [
+        ('/users/{id:int}', {'id': 42}, '/users/42'),
+        ('/users/{id:int}', {'id': '42'}, '/users/42'),
+        ('/prices/{value:float}', {'value': 2.5}, '/prices/2.5'),
+        ('/prices/{value:float}', {'value': '1.50'}, '/prices/1.5'),
+        ('/users/{id:uuid}', {'id': USER_ID.upper()}, f'/users/{USER_ID}'),
+        ('/users/{id:uuid}', {'id': uuid.UUID(USER_ID)}, f'/users/{USER_ID}'),
+        ('/hello/{name}', {'name': 7}, '/hello/7'),
+        ('/static', {}, '/static'),
+    ],
+)
+def test_values_are_rendered_by_convertor(pattern, params, expected):
+    """Test that typed values and their string forms render the same way."""
+    assert build_url(pattern, params) == expected
+
+
+def test_values_are_percent_encoded():
+    """Test that reserved and non-ASCII characters are percent-encoded."""
+    url = build_url('/hello/{name}', {'name': 'café %?# x'})
+    assert url == '/hello/caf%C3%A9%20%25%3F%23%20x'
+
+
+def test_path_convertor_keeps_slashes():
+    """Test that a path parameter may span several segments."""
+    url = build_url('/files/{rest:path}', {'rest': 'docs/my file.txt'})
+    assert url == '/files/docs/my%20file.txt'
+
+
+def test_custom_regex_may_contain_slash():
+    """Test that a slash accepted by a custom regex still matches the route."""
+    pattern = r'/versions/{ver:[0-9]+/[0-9]+}'
+    url = build_url(pattern, {'ver': '1/2'})
+    assert url == '/versions/1/2'
+
+    path_regex, _, _ = compile_path(pattern, CONVERTOR_TYPES)
+    assert re.match(path_regex, url)
+
+
+def test_missing_parameter():
+    """Test that a parameter missing from params raises KeyError."""
+    with pytest.raises(KeyError, match='Missing path parameter: id'):
+        build_url('/users/{id:int}/posts/{slug}', {'slug': 'hi'})
+
+
+@pytest.mark.parametrize(
+    ('pattern', 'params', 'message'),
+    [
+        ('/users/{id:int}', {'id': -1}, 'Negative integers are not supported'),
+        ('/users/{id:int}', {'id': 'abc'}, 'Invalid integer'),
+        ('/prices/{value:float}', {'value': 'cheap'}, 'Invalid float'),
+        ('/users/{id:uuid}', {'id': 'nope'}, 'Invalid UUID'),
+        ('/hello/{name}', {'name': 'a/b'}, 'May not contain path separators'),
+        ('/hello/{name}', {'name': ''}, 'Must not be empty'),
+        (r'/archive/{year:\d{4}}', {'year': '24'}, 'does not match pattern'),
+    ],
+)
+def test_invalid_value_names_parameter(pattern, params, message):
+    """Test that a value rejected by its convertor is a ValueError naming it."""
+    (name,) = params
+    with pytest.raises(ValueError) as exc_info:
+        build_url(pattern, params)
+    assert f"Invalid value for path parameter '{name}'" in str(exc_info.value)
+    assert message in str(exc_info.value)
+
+
+def test_custom_convertor_types():
+    """Test that convertor_types replaces the built-in convertors."""
+    convertor_types = {'str': CONVERTOR_TYPES['int']}
+    assert build_url('/items/{id}', {'id': 3}, convertor_types) == '/items/3'
+    with pytest.raises(KeyError, match='Unknown convertor type: int'):
+        build_url('/items/{id:int}', {'id': 3}, convertor_types)
@@ -0,0 +1,119 @@
+"""Tests for building Set-Cookie header values."""
+
+import pytest
+
+from velithon._velithon import CookieBuilder
+
+# Sun, 06 Nov 1994 08:49:37 GMT
+EXPIRES = 784111777
+
+
+def cookie(name='session', value='abc123'):
+    """Builder with a name and value already set."""
+    return CookieBuilder().name(name).value(value)
+
+
+def test_name_and_value_only():
+    """Test that no attributes are added unless they are set."""
+    assert cookie().build() == 'session=abc123'
+    assert cookie(value='').build() == 'session='
+
+
+def test_attribute_order():
+    """Test that attributes are written in a fixed order, whatever the call order."""
+    header = (
+        cookie()
+        .same_site('lax')
+        .http_only()
+        .secure()
+        .expires(EXPIRES)
+        .max_age(3600)
+        .path('/app')
+        .domain('example.com')
+        .build()
+    )
+    assert header == (
+        'session=abc123; Domain=example.com; Path=/app; Max-Age=3600; '
+        'Expires=Sun, 06 Nov 1994 08:49:37 GMT; Secure; HttpOnly; SameSite=Lax'
+    )
+
+
+@pytest.mark.parametrize(
+    ('max_age', 'expected'),
+    [(0, 'Max-Age=0'), (-1, 'Max-Age=-1'), (86400, 'Max-Age=86400')],
+)
+def test_max_age(max_age, expected):
+    """Test that Max-Age is written as given, including values that expire now."""
+    assert cookie().max_age(max_age).build() == f'session=abc123; {expected}'
+
+
+def test_expires_epoch():
+    """Test that Expires is formatted as an IMF-fixdate."""
+    header = cookie().expires(0).build()
+    assert header == 'session=abc123; Expires=Thu, 01 Jan 1970 00:00:00 GMT'
+
+
+@pytest.mark.parametrize(
+    ('policy', 'expected'), [('strict', 'Strict'), ('LAX', 'Lax'), ('None', 'None')]
+)
+def test_same_site_is_normalized(policy, expected):
+    """Test that SameSite accepts any case and writes the canonical form."""
+    header = cookie().secure().same_site(policy).build()
+    assert header == f'session=abc123; Secure; SameSite={expected}'
+
+
+def test_same_site_none_requires_secure():
+    """Test that SameSite=None without Secure is rejected."""
+    with pytest.raises(ValueError, match='SameSite=None requires the Secure'):
+        cookie().same_site('none').build()
+
+
+def test_invalid_same_site():
+    """Test that unknown SameSite policies are rejected when set."""
+    with pytest.raises(ValueError, match='Invalid SameSite policy'):
+        cookie().same_site('sometimes')
+
+
+@pytest.mark.parametrize(
+    ('value', 'expected'),
+    [
+        ('a b', '"a b"'),
+        ('a;b', '"a;b"'),
+        ('a,b', '"a,b"'),
+        ('say "hi"', '"say \\"hi\\""'),
+        ('back\\slash', '"back\\\\slash"'),
+        ('café', '"café"'),
+        ('a=b/c?d', 'a=b/c?d'),
+    ],
+)
+def test_values_are_quoted_when_needed(value, expected):
+    """Test that values outside cookie-octets are written as quoted strings."""
+    assert cookie(value=value).build() == f'session={expected}'
+
+
+@pytest.mark.parametrize('name', ['', 'two words', 'semi;colon', 'a=b', 'quo"te', 'é'])
+def test_invalid_names(name):
+    """Test that names which are not RFC 7230 tokens are rejected."""
+    with pytest.raises(ValueError, match='Invalid cookie name'):
+        cookie(name=name).build()
+
+
+@pytest.mark.parametrize('value', ['a\r\nSet-Cookie: admin=1', 'tab\there', 'nul\x00'])
+def test_invalid_values(value):
+    """Test that control characters in values are rejected."""
+    with pytest.raises(ValueError, match='Invalid cookie value'):
+        cookie(value=value).build()
+
+
+@pytest.mark.parametrize(
+    ('attribute', 'value'),
+    [
+        ('domain', 'example.com; Secure'),
+        ('path', '/app\r\nX-Injected: 1'),
+    ],
+)
+def test_invalid_attributes(attribute, value):
+    """Test that Domain and Path values cannot break out of the header."""
+    builder = getattr(cookie(), attribute)(value)
+    with pytest.raises(ValueError, match=f'Invalid cookie {attribute.title()}'):
+        builder.build()
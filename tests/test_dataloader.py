@@ -0,0 +1,177 @@
+"""Tests for the batching and caching GraphQL DataLoader."""
+
+import asyncio
+
+import pytest
+
+from velithon._velithon import DataLoader
+
+
+class RecordingBatchFn:
+    """Batch function that records each call and returns key * 10."""
+
+    def __init__(self):
+        """Start with no recorded calls."""
+        self.calls = []
+
+    def __call__(self, keys):
+        """Record the keys and return one value per key."""
+        self.calls.append(list(keys))
+        return [key * 10 for key in keys]
+
+
+class NotAwaitable:
+    """Has an `__await__` attribute but cannot be scheduled by asyncio."""
+
+    __await__ = None
+
+
+def test_constructor_validation():
+    """Test that invalid constructor arguments are rejected."""
+    with pytest.raises(ValueError, match='max_batch_size'):
+        DataLoader(RecordingBatchFn(), max_batch_size=0)
+    with pytest.raises(TypeError, match='callable'):
+        DataLoader('not callable')
+
+
+@pytest.mark.asyncio
+async def test_loads_in_one_tick_share_a_batch():
+    """Test that loads made before yielding to the loop become one call."""
+    batch_fn = RecordingBatchFn()
+    loader = DataLoader(batch_fn)
+
+    values = await asyncio.gather(loader.load(1), loader.load(2), loader.load(3))
+
+    assert values == [10, 20, 30]
+    assert batch_fn.calls == [[1, 2, 3]]
+
+
+@pytest.mark.asyncio
+async def test_load_many():
+    """Test that load_many resolves to values in key order."""
+    batch_fn = RecordingBatchFn()
+    loader = DataLoader(batch_fn)
+
+    assert await loader.load_many([3, 1, 2]) == [30, 10, 20]
+    assert batch_fn.calls == [[3, 1, 2]]
+
+
+@pytest.mark.asyncio
+async def test_max_batch_size_splits_batches():
+    """Test that pending keys are split into batches of max_batch_size."""
+    batch_fn = RecordingBatchFn()
+    loader = DataLoader(batch_fn, max_batch_size=2)
+
+    assert await loader.load_many([1, 2, 3, 4, 5]) == [10, 20, 30, 40, 50]
+    assert batch_fn.calls == [[1, 2], [3, 4], [5]]
+
+
+@pytest.mark.asyncio
+async def test_async_batch_fn():
+    """Test that a coroutine batch function is awaited."""
+    calls = []
+
+    async def batch_fn(keys):
+        calls.append(list(keys))
+        await asyncio.sleep(0)
+        return [f'user-{key}' for key in keys]
+
+    loader = DataLoader(batch_fn)
+    assert await loader.load_many(['a', 'b']) == ['user-a', 'user-b']
+    assert calls == [['a', 'b']]
+
+
+@pytest.mark.asyncio
+async def test_cache_reuses_futures_across_ticks():
+    """Test that cached keys are not fetched again until cleared."""
+    batch_fn = RecordingBatchFn()
+    loader = DataLoader(batch_fn)
+
+    first = loader.load(1)
+    assert loader.load(1) is first
+    assert await first == 10
+    assert await loader.load(1) == 10
+    assert batch_fn.calls == [[1]]
+
+    loader.clear(1)
+    assert await loader.load(1) == 10
+    loader.clear_all()
+    assert await loader.load(1) == 10
+    assert batch_fn.calls == [[1], [1], [1]]
+
+
+@pytest.mark.asyncio
+async def test_cache_disabled():
+    """Test that every load is fetched when caching is off."""
+    batch_fn = RecordingBatchFn()
+    loader = DataLoader(batch_fn, cache=False)
+
+    assert await loader.load(1) == 10
+    assert await loader.load(1) == 10
+    assert batch_fn.calls == [[1], [1]]
+
+
+@pytest.mark.asyncio
+async def test_exception_value_fails_only_that_key():
+    """Test per-key failures and that the failed key is evicted from the cache."""
+    calls = []
+
+    def batch_fn(keys):
+        calls.append(list(keys))
+        return [KeyError(key) if key == 2 and len(calls) == 1 else key for key in keys]
+
+    loader = DataLoader(batch_fn)
+    results = await asyncio.gather(
+        loader.load(1), loader.load(2), loader.load(3), return_exceptions=True
+    )
+
+    assert results[0] == 1
+    assert isinstance(results[1], KeyError)
+    assert results[2] == 3
+    # Only the failed key is fetched again
+    assert await loader.load_many([1, 2, 3]) == [1, 2, 3]
+    assert calls == [[1, 2, 3], [2]]
+
+
+@pytest.mark.asyncio
+async def test_batch_fn_error_fails_batch_and_evicts_keys():
+    """Test that a raising batch function fails every load and allows retries."""
+    calls = []
+
+    def batch_fn(keys):
+        calls.append(list(keys))
+        if len(calls) == 1:
+            raise RuntimeError('database unavailable')
+        return list(keys)
+
+    loader = DataLoader(batch_fn)
+    with pytest.raises(RuntimeError, match='database unavailable'):
+        await loader.load_many([1, 2])
+
+    assert await loader.load_many([1, 2]) == [1, 2]
+    assert calls == [[1, 2], [1, 2]]
+
+
+@pytest.mark.asyncio
+async def test_wrong_number_of_values():
+    """Test that a result of the wrong length fails the batch."""
+    loader = DataLoader(lambda keys: [1])
+    with pytest.raises(ValueError, match='returned 1 values for 2 keys'):
+        await loader.load_many(['a', 'b'])
+
+
+@pytest.mark.asyncio
+async def test_failed_batch_does_not_strand_later_batches():
+    """Test that a batch that cannot be scheduled still lets later batches run."""
+    calls = []
+
+    def batch_fn(keys):
+        calls.append(list(keys))
+        return NotAwaitable() if len(calls) == 1 else list(keys)
+
+    loader = DataLoader(batch_fn, max_batch_size=1)
+    first, second = loader.load(1), loader.load(2)
+
+    with pytest.raises(TypeError):
+        await asyncio.wait_for(first, timeout=1)
+    assert await asyncio.wait_for(second, timeout=1) == 2
@@ -0,0 +1,157 @@
+"""Tests for detecting async factories in the dependency injection helpers."""
+
+import asyncio
+import functools
+import inspect
+
+import pytest
+
+from velithon._velithon import (
+    di_cached_signature_with_metadata,
+    di_call_factory,
+    is_async_callable,
+)
+
+
+async def async_factory(name, count=1):
+    """Async factory returning its arguments."""
+    return (name, count)
+
+
+def sync_factory(name, count=1):
+    """Sync factory returning its arguments."""
+    return (name, count)
+
+
+class AsyncCallable:
+    """Object whose __call__ is a coroutine function."""
+
+    async def __call__(self, name):
+        """Return the name asynchronously."""
+        return name
+
+
+class SyncCallable:
+    """Object whose __call__ is a plain function."""
+
+    def __call__(self, name):
+        """Return the name."""
+        return name
+
+
+class Service:
+    """Class with async methods of every kind."""
+
+    async def method(self):
+        """Async instance method."""
+        return 'method'
+
+    @staticmethod
+    async def static():
+        """Async static method."""
+        return 'static'
+
+    @classmethod
+    async def klass(cls):
+        """Async class method."""
+        return 'klass'
+
+
+ASYNC_CALLABLES = [
+    async_factory,
+    functools.partial(async_factory, 'a'),
+    functools.partial(functools.partial(async_factory), 'a'),
+    AsyncCallable(),
+    functools.partial(AsyncCallable(), 'a'),
+    Service().method,
+    Service.static,
+    Service.klass,
+]
+
+SYNC_CALLABLES = [
+    sync_factory,
+    functools.partial(sync_factory, 'a'),
+    SyncCallable(),
+    functools.partial(SyncCallable(), 'a'),
+    # Calling a class builds an instance, even when its __call__ is async
+    AsyncCallable,
+    lambda: None,
+    print,
+]
+
+
+@pytest.mark.parametrize('func', ASYNC_CALLABLES)
+def test_async_callables_detected(func):
+    """Test async functions, partials of them and async __call__ objects."""
+    assert is_async_callable(func) is True
+    assert di_cached_signature_with_metadata(func)['is_async'] is True
+
+
+@pytest.mark.parametrize('func', SYNC_CALLABLES)
+def test_sync_callables_not_detected(func):
+    """Test that sync callables and classes are not treated as async."""
+    assert is_async_callable(func) is False
+
+
+def test_non_callable():
+    """Test that a value that cannot be called is not async."""
+    assert is_async_callable(42) is False
+
+
+def test_metadata():
+    """Test the signature and parameter count reported with is_async."""
+    metadata = di_cached_signature_with_metadata(async_factory)
+    assert metadata['signature'] == inspect.signature(async_factory)
+    assert metadata['param_count'] == 2
+
+    partial_metadata = di_cached_signature_with_metadata(
+        functools.partial(async_factory, 'a')
+    )
+    assert partial_metadata['param_count'] == 1
+    assert di_cached_signature_with_metadata(AsyncCallable())['param_count'] == 1
+
+
+@pytest.mark.asyncio
+@pytest.mark.parametrize(
+    ('func', 'args', 'expected'),
+    [
+        (async_factory, ['db', 3], ('db', 3)),
+        (functools.partial(async_factory, count=2), ['db'], ('db', 2)),
+        (AsyncCallable(), ['db'], 'db'),
+        (functools.partial(AsyncCallable(), 'db'), [], 'db'),
+    ],
+)
+async def test_call_async_factory_returns_awaitable(func, args, expected):
+    """Test that async factories hand back an awaitable for the caller."""
+    result = di_call_factory(func, args, is_async_callable(func))
+    assert inspect.isawaitable(result)
+    assert await result == expected
+
+
+def test_call_sync_factory():
+    """Test that sync factories return their value directly."""
+    assert di_call_factory(sync_factory, ['db'], False) == ('db', 1)
+    assert di_call_factory(SyncCallable(), ['db']) == 'db'
+
+
+def test_async_factory_returning_plain_value():
+    """Test that an async factory returning a non-awaitable is rejected."""
+    with pytest.raises(TypeError, match='returned a non-awaitable value'):
+        di_call_factory(sync_factory, ['db'], True)
+
+
+def test_factory_errors_propagate():
+    """Test that exceptions raised by the factory reach the caller."""
+
+    def broken():
+        raise RuntimeError('no database')
+
+    with pytest.raises(RuntimeError, match='no database'):
+        di_call_factory(broken, [])
+
+    async def broken_async():
+        raise RuntimeError('no cache')
+
+    coroutine = di_call_factory(broken_async, [], True)
+    with pytest.raises(RuntimeError, match='no cache'):
+        asyncio.run(coroutine)
@@ -0,0 +1,238 @@
+"""
+Tests for the Rust-backed event channel.
+"""
+
+import asyncio
+import sys
+
+import pytest
+
+from velithon.event import EventChannel
+
+
+@pytest.fixture
+def hold_gil():
+    """Keep the GIL on the test thread so listener dispatch cannot drain channels."""
+    interval = sys.getswitchinterval()
+    sys.setswitchinterval(60)
+    yield
+    sys.setswitchinterval(interval)
+
+
+async def wait_for(predicate, timeout: float = 2.0):
+    """Poll until predicate() is true or the timeout expires."""
+    deadline = asyncio.get_running_loop().time() + timeout
+    while not predicate():
+        if asyncio.get_running_loop().time() > deadline:
+            raise AssertionError('condition not met before timeout')
+        await asyncio.sleep(0.01)
+
+
+class TestBackpressure:
+    """Test what emit does when a listener's channel is full."""
+
+    def test_invalid_strategy(self):
+        """Test that an unknown backpressure strategy is rejected."""
+        with pytest.raises(ValueError, match='Invalid backpressure strategy'):
+            EventChannel(backpressure_strategy='spill')
+
+    @pytest.mark.asyncio
+    async def test_drop_discards_and_counts(self, hold_gil):
+        """Test that 'drop' discards events once the channel is full."""
+        channel = EventChannel(buffer_size=1, backpressure_strategy='drop')
+        channel.register_listener(
+            'slow', lambda data: None, False, asyncio.get_running_loop()
+        )
+
+        # Emitting never suspends here, so the dispatcher cannot take the GIL
+        # and at most one event leaves the channel before it fills up
+        for i in range(5):
+            await channel.emit('slow', {'i': i})
+
+        assert channel.get_channel_depth('slow') == 1
+        assert channel.get_dropped_count('slow') >= 3
+        assert channel.get_dropped_count('other') == 0
+        await channel.cleanup()
+
+    @pytest.mark.asyncio
+    async def test_error_raises_when_full(self, hold_gil):
+        """Test that 'error' raises RuntimeError once the channel is full."""
+        channel = EventChannel(buffer_size=1, backpressure_strategy='error')
+        channel.register_listener(
+            'slow', lambda data: None, False, asyncio.get_running_loop()
+        )
+
+        with pytest.raises(RuntimeError, match="Channel for event 'slow' is full"):
+            for i in range(5):
+                await channel.emit('slow', {'i': i})
+
+        assert channel.get_dropped_count('slow') == 0
+        await channel.cleanup()
+
+    @pytest.mark.asyncio
+    async def test_block_delivers_every_event(self):
+        """Test that 'block' waits for room instead of losing events."""
+        channel = EventChannel(buffer_size=1, backpressure_strategy='block')
+        received = []
+
+        def slow_listener(data):
+            received.append(data['i'])
+
+        channel.register_listener(
+            'slow', slow_listener, False, asyncio.get_running_loop()
+        )
+        for i in range(20):
+            await channel.emit('slow', {'i': i})
+
+        await wait_for(lambda: len(received) == 20)
+        assert sorted(received) == list(range(20))
+        assert channel.get_dropped_count('slow') == 0
+        assert channel.get_channel_depth('slow') == 0
+        await channel.cleanup()
+
+    @pytest.mark.asyncio
+    async def test_emit_without_listener(self):
+        """Test that events without listeners are ignored."""
+        channel = EventChannel(buffer_size=1, backpressure_strategy='error')
+        for i in range(3):
+            await channel.emit('nobody', {'i': i})
+        assert channel.get_channel_depth('nobody') == 0
+
+
+USER_CREATED_SCHEMA = {
+    'type': 'object',
+    'required': ['user_id', 'email'],
+    'properties': {
+        'user_id': {'type': 'integer'},
+        'email': {'type': 'string'},
+        'role': {'enum': ['admin', 'member']},
+        'tags': {'type': 'array', 'items': {'type': 'string'}},
+    },
+    'additionalProperties': False,
+}
+
+
+class TestEventSchema:
+    """Test schema validation of emitted events."""
+
+    def test_validate_event(self):
+        """Test that every violation is reported with its path."""
+        channel = EventChannel()
+        channel.register_event_schema('user.created', USER_CREATED_SCHEMA)
+
+        valid = {'user_id': 1, 'email': 'a@b'}
+        assert channel.validate_event('user.created', valid) == []
+        errors = channel.validate_event(
+            'user.created',
+            {'user_id': 'one', 'role': 'owner', 'tags': ['x', 2], 'extra': True},
+        )
+        assert sorted(errors) == sorted(
+            [
+                "$: missing required field 'email'",
+                "$.user_id: expected type 'integer', got 'string'",
+                '$.role: value "owner" is not one of the allowed values',
+                "$.tags[1]: expected type 'string', got 'integer'",
+                "$: unexpected field 'extra'",
+            ]
+        )
+
+    def test_tuples_validate_as_arrays(self):
+        """Test that tuple payload values are checked as JSON arrays."""
+        channel = EventChannel()
+        channel.register_event_schema('user.created', USER_CREATED_SCHEMA)
+
+        event = {'user_id': 1, 'email': 'a@b', 'tags': ('x', 'y')}
+        assert channel.validate_event('user.created', event) == []
+        errors = channel.validate_event(
+            'user.created', {'user_id': 1, 'email': 'a@b', 'tags': ('x', 2)}
+        )
+        assert errors == ["$.tags[1]: expected type 'string', got 'integer'"]
+
+    def test_validate_event_without_schema(self):
+        """Test that events without a schema always validate."""
+        channel = EventChannel()
+        assert channel.validate_event('anything', {'x': object()}) == []
+
+    @pytest.mark.asyncio
+    async def test_invalid_events_are_not_delivered(self):
+        """Test that emit rejects invalid data and counts it."""
+        channel = EventChannel(record_history=True)
+        channel.register_event_schema('user.created', USER_CREATED_SCHEMA)
+        received = []
+        channel.register_listener(
+            'user.created', received.append, False, asyncio.get_running_loop()
+        )
+
+        await channel.emit('user.created', {'user_id': 'bad'})
+        await channel.emit('user.created', {'user_id': 2, 'email': 'c@d'})
+
+        await wait_for(lambda: len(received) == 1)
+        await asyncio.sleep(0.05)
+        assert received == [{'user_id': 2, 'email': 'c@d'}]
+        assert channel.get_rejected_count('user.created') == 1
+        # Rejected events are not recorded either
+        assert len(channel.get_history('user.created')) == 1
+        await channel.cleanup()
+        assert channel.get_rejected_count('user.created') == 0
+
+
+class TestEventHistory:
+    """Test recording and replaying emitted events."""
+
+    @pytest.mark.asyncio
+    async def test_history_disabled_by_default(self):
+        """Test that nothing is recorded unless record_history is set."""
+        channel = EventChannel()
+        await channel.emit('tick', {'n': 1})
+        assert channel.get_history() == []
+
+    @pytest.mark.asyncio
+    async def test_history_is_bounded(self):
+        """Test that the oldest events are discarded past max_history_size."""
+        channel = EventChannel(record_history=True, max_history_size=2)
+        for n in range(3):
+            await channel.emit('tick', {'n': n})
+
+        history = channel.get_history()
+        assert [entry['data'] for entry in history] == [{'n': 1}, {'n': 2}]
+        assert all(entry['event_name'] == 'tick' for entry in history)
+        assert history[0]['timestamp'] <= history[1]['timestamp']
+
+    @pytest.mark.asyncio
+    async def test_history_filter_and_clear(self):
+        """Test filtering and clearing history by event name."""
+        channel = EventChannel(record_history=True)
+        await channel.emit('a', {'n': 1})
+        await channel.emit('b', {'n': 2})
+        await channel.emit('a', {'n': 3})
+
+        assert [e['data']['n'] for e in channel.get_history('a')] == [1, 3]
+        channel.clear_history('a')
+        assert [e['event_name'] for e in channel.get_history()] == ['b']
+        channel.clear_history()
+        assert channel.get_history() == []
+
+    @pytest.mark.asyncio
+    async def test_replay_to_late_listener(self):
+        """Test that events emitted before a listener existed can be replayed."""
+        channel = EventChannel(record_history=True)
+        for n in range(4):
+            await channel.emit('order', {'n': n})
+        await channel.emit('other', {'n': 99})
+
+        received = []
+        channel.register_listener(
+            'order', received.append, False, asyncio.get_running_loop()
+        )
+
+        # Events without a listener are skipped
+        assert channel.replay('other') == 0
+        assert channel.replay('order', limit=2) == 2
+        await wait_for(lambda: len(received) == 2)
+        assert sorted(data['n'] for data in received) == [2, 3]
+
+        received.clear()
+        assert channel.replay('order', since=channel.get_history()[0]['timestamp']) == 4
+        assert channel.replay('order', since=2**40) == 0
+        await wait_for(lambda: len(received) == 4)
+        await channel.cleanup()
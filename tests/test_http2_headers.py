@@ -0,0 +1,206 @@
+"""Tests for HTTP/2 pseudo-header handling."""
+
+import pytest
+
+from velithon._velithon import (
+    build_http2_request_headers,
+    split_http2_headers,
+    validate_http2_pseudo_headers,
+)
+
+REQUEST_PSEUDO = {
+    ':method': 'GET',
+    ':scheme': 'https',
+    ':authority': 'example.com',
+    ':path': '/',
+}
+
+
+class TestSplit:
+    """Test splitting a received header list."""
+
+    def test_split(self):
+        """Test that pseudo and regular headers are separated in order."""
+        pseudo, regular = split_http2_headers(
+            [
+                (':method', 'GET'),
+                (':path', '/items'),
+                ('accept', 'text/html'),
+                ('user-agent', 'test'),
+            ]
+        )
+        assert pseudo == {':method': 'GET', ':path': '/items'}
+        assert regular == {'accept': 'text/html', 'user-agent': 'test'}
+        assert list(regular) == ['accept', 'user-agent']
+
+    def test_pseudo_header_after_regular_header(self):
+        """Test that a pseudo-header following a regular header is rejected."""
+        with pytest.raises(ValueError, match=':path appears after a regular header'):
+            split_http2_headers([(':method', 'GET'), ('accept', '*/*'), (':path', '/')])
+
+    def test_duplicate_pseudo_header(self):
+        """Test that a pseudo-header may only appear once."""
+        with pytest.raises(ValueError, match='Duplicate pseudo-header: :path'):
+            split_http2_headers([(':path', '/a'), (':PATH', '/b')])
+
+    def test_uppercase_names_are_lowercased(self):
+        """Test that names are lowercased before pseudo and duplicate checks."""
+        pseudo, regular = split_http2_headers(
+            [(':Method', 'GET'), ('Accept', 'text/html'), ('ACCEPT', 'text/plain')]
+        )
+        assert pseudo == {':method': 'GET'}
+        assert regular == {'accept': 'text/html, text/plain'}
+
+    def test_repeated_headers_are_joined(self):
+        """Test that cookies are joined with '; ' and other headers with ', '."""
+        _, regular = split_http2_headers(
+            [('cookie', 'a=1'), ('x-list', 'one'), ('cookie', 'b=2'), ('x-list', 'two')]
+        )
+        assert regular == {'cookie': 'a=1; b=2', 'x-list': 'one, two'}
+
+    def test_empty(self):
+        """Test that no headers give two empty dicts."""
+        assert split_http2_headers([]) == ({}, {})
+
+
+class TestValidate:
+    """Test validation of request and response pseudo-headers."""
+
+    def test_valid_request(self):
+        """Test that a complete request has no errors."""
+        assert validate_http2_pseudo_headers(REQUEST_PSEUDO) == []
+
+    @pytest.mark.parametrize('missing', [':scheme', ':path'])
+    def test_missing_scheme_or_path(self, missing):
+        """Test that :scheme and :path are required outside CONNECT."""
+        pseudo = {k: v for k, v in REQUEST_PSEUDO.items() if k != missing}
+        assert validate_http2_pseudo_headers(pseudo) == [
+            f'Missing required pseudo-header: {missing}'
+        ]
+
+    def test_missing_method(self):
+        """Test that a request without :method reports only that."""
+        pseudo = {k: v for k, v in REQUEST_PSEUDO.items() if k != ':method'}
+        assert validate_http2_pseudo_headers(pseudo) == [
+            'Missing required pseudo-header: :method'
+        ]
+
+    def test_all_missing(self):
+        """Test that an empty pseudo-header set reports the missing :method."""
+        assert validate_http2_pseudo_headers({}) == [
+            'Missing required pseudo-header: :method'
+        ]
+
+    def test_every_problem_is_reported(self):
+        """Test that unknown names and invalid values are reported together."""
+        errors = validate_http2_pseudo_headers(
+            {':method': 'G T', ':path': 'items', ':protocol': 'x', ':zzz': 'y'}
+        )
+        assert errors == [
+            'Unknown pseudo-header: :protocol',
+            'Unknown pseudo-header: :zzz',
+            'Invalid :method value: G T',
+            'Missing required pseudo-header: :scheme',
+            'Invalid :path value: items',
+        ]
+
+    def test_options_asterisk(self):
+        """Test that '*' is only a valid :path for OPTIONS."""
+        options = {**REQUEST_PSEUDO, ':method': 'OPTIONS', ':path': '*'}
+        assert validate_http2_pseudo_headers(options) == []
+        get = {**REQUEST_PSEUDO, ':path': '*'}
+        assert validate_http2_pseudo_headers(get) == ['Invalid :path value: *']
+
+    def test_connect(self):
+        """Test that CONNECT needs :authority and forbids :scheme and :path."""
+        assert (
+            validate_http2_pseudo_headers(
+                {':method': 'CONNECT', ':authority': 'example.com:443'}
+            )
+            == []
+        )
+        assert validate_http2_pseudo_headers(
+            {':method': 'CONNECT', ':scheme': 'https', ':path': '/'}
+        ) == [
+            'Missing required pseudo-header: :authority',
+            'Pseudo-header :scheme is not allowed with CONNECT',
+            'Pseudo-header :path is not allowed with CONNECT',
+        ]
+
+    @pytest.mark.parametrize(
+        ('pseudo', 'expected'),
+        [
+            ({':status': '200'}, []),
+            ({':status': '99'}, ['Invalid :status value: 99']),
+            ({':status': '600'}, ['Invalid :status value: 600']),
+            ({':status': '2xx'}, ['Invalid :status value: 2xx']),
+            (
+                {':status': '204', ':path': '/'},
+                ['Pseudo-header :path is not allowed in a response'],
+            ),
+        ],
+    )
+    def test_response(self, pseudo, expected):
+        """Test response pseudo-header validation."""
+        assert validate_http2_pseudo_headers(pseudo) == expected
+
+
+class TestBuild:
+    """Test building an outgoing request header list."""
+
+    def test_pseudo_headers_first(self):
+        """Test that pseudo-headers come first, followed by lowercase headers."""
+        headers = build_http2_request_headers(
+            'POST', '/items', 'example.com', {'Content-Type': 'application/json'}
+        )
+        assert headers == [
+            (':method', 'POST'),
+            (':scheme', 'https'),
+            (':authority', 'example.com'),
+            (':path', '/items'),
+            ('content-type', 'application/json'),
+        ]
+
+    def test_connection_specific_headers_are_dropped(self):
+        """Test that HTTP/1-only headers and Host are removed."""
+        headers = build_http2_request_headers(
+            'GET',
+            '/',
+            'example.com',
+            {
+                'Host': 'example.com',
+                'Connection': 'keep-alive',
+                'Keep-Alive': 'timeout=5',
+                'Proxy-Connection': 'keep-alive',
+                'Transfer-Encoding': 'chunked',
+                'Upgrade': 'h2c',
+                'TE': 'gzip',
+                'Accept': '*/*',
+            },
+            scheme='http',
+        )
+        assert headers[4:] == [('accept', '*/*')]
+        assert headers[1] == (':scheme', 'http')
+
+    def test_te_trailers_is_kept(self):
+        """Test that TE is allowed with the value 'trailers'."""
+        headers = build_http2_request_headers('GET', '/', 'a', {'TE': ' Trailers '})
+        assert headers[4:] == [('te', ' Trailers ')]
+
+    def test_pseudo_header_in_regular_headers(self):
+        """Test that pseudo-headers may not be passed as regular headers."""
+        with pytest.raises(ValueError, match='not allowed in regular headers'):
+            build_http2_request_headers('GET', '/', 'a', {':status': '200'})
+
+    @pytest.mark.parametrize(
+        ('method', 'path', 'message'),
+        [
+            ('GET', 'items', 'Invalid :path value: items'),
+            ('BAD METHOD', '/', 'Invalid :method value: BAD METHOD'),
+            ('', '/', 'Invalid :method value: '),
+        ],
+    )
+    def test_invalid_pseudo_values(self, method, path, message):
+        """Test that invalid :method and :path values are rejected."""
+        with pytest.raises(ValueError, match=message):
+            build_http2_request_headers(method, path, 'example.com', {})
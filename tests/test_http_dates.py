@@ -0,0 +1,90 @@
+"""Tests for HTTP-date parsing, formatting and conditional GET checks."""
+
+import pytest
+
+from velithon._velithon import format_http_date, is_not_modified, parse_http_date
+
+# The example date from RFC 7231 section 7.1.1.1
+RFC_EXAMPLE = 784111777
+IMF_FIXDATE = 'Sun, 06 Nov 1994 08:49:37 GMT'
+
+
+@pytest.mark.parametrize(
+    'date',
+    [
+        IMF_FIXDATE,
+        'Sunday, 06-Nov-94 08:49:37 GMT',
+        'Sun Nov  6 08:49:37 1994',
+        f'  {IMF_FIXDATE} ',
+    ],
+)
+def test_rfc_7231_formats(date):
+    """Test the IMF-fixdate, RFC 850 and asctime forms of the RFC example."""
+    assert parse_http_date(date) == RFC_EXAMPLE
+
+
+@pytest.mark.parametrize(
+    ('date', 'timestamp'),
+    [
+        ('Wed Nov 16 08:49:37 1994', RFC_EXAMPLE + 10 * 86400),
+        ('Tuesday, 09-Nov-10 00:00:00 GMT', 1289260800),
+        ('Thu, 01 Jan 1970 00:00:00 GMT', 0),
+    ],
+)
+def test_other_dates(date, timestamp):
+    """Test a two-digit day in asctime, a 20xx RFC 850 year and the epoch."""
+    assert parse_http_date(date) == timestamp
+
+
+@pytest.mark.parametrize('timestamp', [0, RFC_EXAMPLE, 1_700_000_000, 4_102_444_800])
+def test_format_parse_round_trip(timestamp):
+    """Test that formatted dates are IMF-fixdates that parse back unchanged."""
+    formatted = format_http_date(timestamp)
+    assert formatted.endswith(' GMT')
+    assert parse_http_date(formatted) == timestamp
+
+
+def test_format_example():
+    """Test formatting the RFC example timestamp."""
+    assert format_http_date(RFC_EXAMPLE) == IMF_FIXDATE
+
+
+def test_format_out_of_range():
+    """Test that timestamps chrono cannot represent are rejected."""
+    with pytest.raises(ValueError, match='Timestamp out of range'):
+        format_http_date(2**63)
+
+
+@pytest.mark.parametrize(
+    'date',
+    [
+        '',
+        'yesterday',
+        '784111777',
+        'Sun, 06 Nov 1994 08:49:37 PST',
+        'Mon, 06 Nov 1994 08:49:37 GMT',
+        'Sun, 31 Nov 1994 08:49:37 GMT',
+        'Sun, 06 Nov 1994 25:49:37 GMT',
+        'Wed, 31 Dec 1969 23:59:59 GMT',
+    ],
+)
+def test_invalid_dates(date):
+    """Test that malformed, inconsistent and pre-epoch dates raise ValueError."""
+    with pytest.raises(ValueError, match='Invalid HTTP date'):
+        parse_http_date(date)
+
+
+@pytest.mark.parametrize(
+    ('last_modified', 'if_modified_since', 'expected'),
+    [
+        (RFC_EXAMPLE, IMF_FIXDATE, True),
+        (RFC_EXAMPLE - 1, IMF_FIXDATE, True),
+        (RFC_EXAMPLE + 1, IMF_FIXDATE, False),
+        (RFC_EXAMPLE, 'Sunday, 06-Nov-94 08:49:37 GMT', True),
+        (RFC_EXAMPLE, 'not a date', False),
+        (RFC_EXAMPLE, '', False),
+    ],
+)
+def test_is_not_modified(last_modified, if_modified_since, expected):
+    """Test equal dates count as unmodified and invalid headers are ignored."""
+    assert is_not_modified(last_modified, if_modified_since) is expected
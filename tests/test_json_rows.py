@@ -0,0 +1,106 @@
+"""Tests for the Rust row encoder used for lists of homogeneous dicts."""
+
+import json
+
+import pytest
+
+from velithon._velithon import encode_rows
+
+
+def test_rows_match_json_dumps():
+    """Test that encoded rows decode to the input with key order preserved."""
+    rows = [
+        {'id': i, 'name': f'user-{i}', 'score': i * 0.5, 'active': i % 2 == 0}
+        for i in range(3)
+    ]
+    encoded = encode_rows(rows)
+    assert encoded == json.dumps(rows, separators=(',', ':')).encode()
+    assert list(json.loads(encoded)[0]) == ['id', 'name', 'score', 'active']
+
+
+def test_empty_rows():
+    """Test that no rows encode as an empty array."""
+    assert encode_rows([]) == b'[]'
+
+
+def test_schema_hint_sets_key_order():
+    """Test that the schema hint order is used instead of the row's own order."""
+    rows = [{'b': 1, 'a': 2}, {'a': 3, 'b': 4}]
+    assert encode_rows(rows, ['a', 'b']) == b'[{"a":2,"b":1},{"a":3,"b":4}]'
+
+
+def test_mixed_key_rows_fall_back():
+    """Test that rows with a different key set are encoded as they are."""
+    rows = [{'id': 1, 'name': 'a'}, {'id': 2}, {'id': 3, 'other': None}, {'x': 1}]
+    assert json.loads(encode_rows(rows)) == rows
+    assert json.loads(encode_rows(rows, ['name', 'id'])) == rows
+
+
+def test_nested_values_and_special_cases():
+    """Test nested containers, big ints, non-finite floats and unknown types."""
+    rows = [
+        {
+            'tags': ['a', ('b', 'c')],
+            'meta': {'nested': {'n': None}, 1: 'int key'},
+            'big': 2**70,
+            'nan': float('nan'),
+            'text': 'quote " and é',
+            'obj': object.__new__(type('Point', (), {'__str__': lambda self: 'pt'})),
+        }
+    ]
+    assert json.loads(encode_rows(rows)) == [
+        {
+            'tags': ['a', ['b', 'c']],
+            'meta': {'nested': {'n': None}, '1': 'int key'},
+            'big': 2**70,
+            'nan': None,
+            'text': 'quote " and é',
+            'obj': 'pt',
+        }
+    ]
+
+
+def test_rows_must_be_dicts():
+    """Test that non-dict rows raise TypeError."""
+    with pytest.raises(TypeError, match='expects a list of dicts'):
+        encode_rows([{'a': 1}, ['a', 1]])
+
+
+@pytest.mark.parametrize('kind', ['list', 'dict', 'row'])
+def test_circular_reference_rejected(kind):
+    """Test that a container that contains itself raises ValueError."""
+    if kind == 'list':
+        value = []
+        value.append(value)
+        row = {'value': value}
+    elif kind == 'dict':
+        value = {}
+        value['self'] = value
+        row = {'value': value}
+    else:
+        row = {}
+        row['value'] = row
+
+    with pytest.raises(ValueError, match='Circular reference detected'):
+        encode_rows([row])
+
+
+def test_shared_container_is_not_circular():
+    """Test that the same container may appear more than once side by side."""
+    shared = [1, 2]
+    rows = [{'a': shared, 'b': [shared, shared]}, {'a': shared, 'b': []}]
+    assert json.loads(encode_rows(rows)) == rows
+
+
+def test_deep_nesting_rejected():
+    """Test that nesting beyond the depth limit raises ValueError."""
+    value = []
+    for _ in range(10_000):
+        value = [value]
+    with pytest.raises(ValueError, match='Maximum nesting depth'):
+        encode_rows([{'value': value}])
+
+    shallow = []
+    for _ in range(100):
+        shallow = [shallow]
+    assert json.loads(encode_rows([{'value': shallow}])) == [{'value': shallow}]
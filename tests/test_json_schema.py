@@ -0,0 +1,148 @@
+"""Tests for generating JSON Schema documents from Python type hints."""
+
+import json
+from typing import Any, Dict, List, Literal, Optional, Union
+
+import pytest
+
+from velithon._velithon import generate_json_schema
+
+
+def schema(annotation):
+    """Generate and decode the schema for an annotation."""
+    return json.loads(generate_json_schema(annotation))
+
+
+@pytest.mark.parametrize(
+    ('annotation', 'expected'),
+    [
+        (str, {'type': 'string'}),
+        (int, {'type': 'integer'}),
+        (float, {'type': 'number'}),
+        (bool, {'type': 'boolean'}),
+        (list, {'type': 'array'}),
+        (dict, {'type': 'object'}),
+        (None, {'type': 'null'}),
+        (type(None), {'type': 'null'}),
+        (Any, {}),
+    ],
+)
+def test_scalar_types(annotation, expected):
+    """Test plain annotations map to their JSON types."""
+    assert schema(annotation) == expected
+
+
+@pytest.mark.parametrize(
+    ('annotation', 'expected'),
+    [
+        (
+            dict[str, list[int]],
+            {
+                'type': 'object',
+                'additionalProperties': {
+                    'type': 'array',
+                    'items': {'type': 'integer'},
+                },
+            },
+        ),
+        (
+            List[Dict[str, float]],
+            {
+                'type': 'array',
+                'items': {
+                    'type': 'object',
+                    'additionalProperties': {'type': 'number'},
+                },
+            },
+        ),
+        (
+            dict[str, dict[str, list[bool]]],
+            {
+                'type': 'object',
+                'additionalProperties': {
+                    'type': 'object',
+                    'additionalProperties': {
+                        'type': 'array',
+                        'items': {'type': 'boolean'},
+                    },
+                },
+            },
+        ),
+        (
+            list[list[None]],
+            {'type': 'array', 'items': {'type': 'array', 'items': {'type': 'null'}}},
+        ),
+    ],
+)
+def test_nested_containers(annotation, expected):
+    """Test that generic arguments are recursed into."""
+    assert schema(annotation) == expected
+
+
+@pytest.mark.parametrize('annotation', [list[int | str], List[Union[int, str]]])
+def test_mixed_type_list(annotation):
+    """Test a list of several item types lists each alternative."""
+    assert schema(annotation) == {
+        'type': 'array',
+        'items': {'anyOf': [{'type': 'integer'}, {'type': 'string'}]},
+    }
+
+
+@pytest.mark.parametrize('annotation', [Optional[int], int | None, Union[None, int]])
+def test_optional(annotation):
+    """Test Optional[T] is T or null."""
+    assert schema(annotation) == {'oneOf': [{'type': 'integer'}, {'type': 'null'}]}
+
+
+def test_optional_items():
+    """Test nullable list items."""
+    assert schema(list[Optional[str]]) == {
+        'type': 'array',
+        'items': {'oneOf': [{'type': 'string'}, {'type': 'null'}]},
+    }
+
+
+def test_nullable_union_of_several_types():
+    """Test a union of several types and None allows any of them."""
+    assert schema(Union[int, str, None]) == {
+        'anyOf': [{'type': 'integer'}, {'type': 'string'}, {'type': 'null'}]
+    }
+
+
+def test_literal():
+    """Test Literal values become an enum."""
+    assert schema(Literal['a', 1, None]) == {'enum': ['a', 1, None]}
+
+
+@pytest.mark.parametrize(
+    ('annotation', 'expected'),
+    [
+        (
+            tuple[int, ...],
+            {'type': 'array', 'items': {'type': 'integer'}},
+        ),
+        (
+            tuple[int, str],
+            {
+                'type': 'array',
+                'prefixItems': [{'type': 'integer'}, {'type': 'string'}],
+                'minItems': 2,
+                'maxItems': 2,
+            },
+        ),
+        (
+            set[str],
+            {'type': 'array', 'items': {'type': 'string'}, 'uniqueItems': True},
+        ),
+    ],
+)
+def test_tuples_and_sets(annotation, expected):
+    """Test tuple and set annotations map to arrays."""
+    assert schema(annotation) == expected
+
+
+@pytest.mark.parametrize('annotation', [dict[int, str], bytes, object])
+def test_unsupported_annotations(annotation):
+    """Test annotations without a JSON equivalent are rejected."""
+    with pytest.raises(TypeError):
+        generate_json_schema(annotation)
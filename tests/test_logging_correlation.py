@@ -0,0 +1,152 @@
+"""Tests for per-request log correlation IDs."""
+
+import asyncio
+from unittest.mock import MagicMock, patch
+
+import pytest
+
+from velithon.logging import (
+    get_correlation_id,
+    get_logger,
+    reset_correlation_id,
+    set_correlation_id,
+)
+from velithon.middleware.logging import LoggingMiddleware
+
+
+class RecordingLog:
+    """Stand-in for a Rust `log_*_with_extra` function."""
+
+    def __init__(self):
+        """Start with no recorded records."""
+        self.records = []
+
+    def __call__(self, msg, module, line, extra):
+        """Record the message and its extra fields."""
+        self.records.append((msg, extra))
+
+
+def make_scope(request_id=None):
+    """Mock scope whose request ID is generated lazily, like the real Scope."""
+    scope = MagicMock()
+    scope._request_id = request_id
+    scope.method = 'GET'
+    scope.headers = {}
+
+    def generate():
+        if scope._request_id is None:
+            scope._request_id = f'generated-{scope.path}'
+        return scope._request_id
+
+    type(scope).request_id = property(lambda self: generate())
+    return scope
+
+
+def test_set_and_reset():
+    """Test that the ID is set for the current context and reset afterwards."""
+    assert get_correlation_id() is None
+    token = set_correlation_id('abc')
+    assert get_correlation_id() == 'abc'
+    inner = set_correlation_id(lambda: 'lazy')
+    assert get_correlation_id() == 'lazy'
+    reset_correlation_id(inner)
+    assert get_correlation_id() == 'abc'
+    reset_correlation_id(token)
+    assert get_correlation_id() is None
+
+
+def test_logged_records_carry_correlation_id():
+    """Test that every level adds the ID unless extra already has one."""
+    logger = get_logger()
+    recorders = {name: RecordingLog() for name in ('debug', 'info', 'warn')}
+    token = set_correlation_id('req-1')
+    try:
+        with (
+            patch('velithon.logging.rust_log_debug_with_extra', recorders['debug']),
+            patch('velithon.logging.rust_log_info_with_extra', recorders['info']),
+            patch('velithon.logging.rust_log_warn_with_extra', recorders['warn']),
+        ):
+            logger.debug('debug')
+            logger.info('info %s', 1, extra={'user': 7})
+            logger.warning('warn', extra={'correlation_id': 'explicit'})
+    finally:
+        reset_correlation_id(token)
+
+    assert recorders['debug'].records == [('debug', {'correlation_id': 'req-1'})]
+    assert recorders['info'].records == [
+        ('info 1', {'user': '7', 'correlation_id': 'req-1'})
+    ]
+    assert recorders['warn'].records == [('warn', {'correlation_id': 'explicit'})]
+
+
+def test_extra_none():
+    """Test that extra=None is treated like no extra fields."""
+    logger = get_logger()
+    calls = []
+    with (
+        patch('velithon.logging.rust_log_info', lambda *args: calls.append(args)),
+        patch('velithon.logging.rust_log_error', lambda *args: calls.append(args)),
+    ):
+        logger.info('info', extra=None)
+        logger.error('error', extra=None)
+    assert [call[0] for call in calls] == ['info', 'error']
+
+    recorder = RecordingLog()
+    token = set_correlation_id('req-2')
+    try:
+        with patch('velithon.logging.rust_log_debug_with_extra', recorder):
+            logger.debug('debug', extra=None)
+    finally:
+        reset_correlation_id(token)
+    assert recorder.records == [('debug', {'correlation_id': 'req-2'})]
+
+
+@pytest.mark.asyncio
+async def test_concurrent_requests_keep_their_own_id():
+    """Test that interleaved requests do not see each other's correlation ID."""
+    logger = get_logger()
+    recorder = RecordingLog()
+    started = []
+
+    async def app(scope, protocol):
+        # Wait until every request has started, so their handlers interleave
+        started.append(scope.path)
+        while len(started) < 3:
+            await asyncio.sleep(0)
+        logger.info('handling %s', scope.path)
+
+    middleware = LoggingMiddleware(app)
+    scopes = [make_scope('id-a'), make_scope('id-b'), make_scope()]
+    for scope, path in zip(scopes, ['/a', '/b', '/c']):
+        scope.path = path
+
+    with (
+        patch('velithon.logging.rust_is_enabled_for', lambda level: True),
+        patch('velithon.logging.rust_log_info_with_extra', recorder),
+    ):
+        await asyncio.gather(
+            *(middleware.process_http_request(s, MagicMock()) for s in scopes)
+        )
+
+    handled = {
+        msg: extra['correlation_id']
+        for msg, extra in recorder.records
+        if msg.startswith('handling')
+    }
+    assert handled == {
+        'handling /a': 'id-a',
+        'handling /b': 'id-b',
+        'handling /c': 'generated-/c',
+    }
+    processed = {
+        extra['path']: (extra['request_id'], extra['correlation_id'])
+        for msg, extra in recorder.records
+        if msg.startswith('Processed')
+    }
+    # The logged request_id is read before the handler runs, as it was
+    assert processed == {
+        '/a': ('id-a', 'id-a'),
+        '/b': ('id-b', 'id-b'),
+        '/c': ('None', 'generated-/c'),
+    }
+    assert get_correlation_id() is None
@@ -0,0 +1,138 @@
+"""Tests for path template compilation and custom regex parameters."""
+
+import re
+
+import pytest
+
+from velithon._velithon import (
+    FloatConvertor,
+    IntegerConvertor,
+    PathConvertor,
+    RegexConvertor,
+    StringConvertor,
+    UUIDConvertor,
+    compile_path,
+    decompile_path,
+)
+from velithon.convertors import CONVERTOR_TYPES
+
+
+def test_typed_parameter_unchanged():
+    """Test that `{id:int}` compiles exactly as before custom regexes."""
+    path_regex, path_format, convertors = compile_path(
+        '/users/{id:int}', CONVERTOR_TYPES
+    )
+    assert path_regex == '^/users/(?P<id>[0-9]+)$'
+    assert path_format == '/users/{id}'
+    assert convertors == {'id': CONVERTOR_TYPES['int']}
+
+
+def test_untyped_parameter_defaults_to_str():
+    """Test that `{name}` uses the string convertor."""
+    path_regex, _, convertors = compile_path('/hello/{name}', CONVERTOR_TYPES)
+    assert path_regex == '^/hello/(?P<name>[^/]+)$'
+    assert convertors['name'] is CONVERTOR_TYPES['str']
+
+
+def test_unknown_type_name_raises_key_error():
+    """Test that an unknown convertor type name is still a KeyError."""
+    with pytest.raises(KeyError, match='Unknown convertor type: bogus'):
+        compile_path('/users/{id:bogus}', CONVERTOR_TYPES)
+
+
+def test_duplicate_parameters_rejected():
+    """Test that a parameter name may only appear once."""
+    with pytest.raises(ValueError, match='Duplicate parameters found: id'):
+        compile_path('/users/{id}/{id:int}', CONVERTOR_TYPES)
+
+
+def test_custom_regex_parameter():
+    """Test a regex constraint containing its own braces."""
+    path_regex, path_format, convertors = compile_path(
+        r'/archive/{year:\d{4}}/{slug:[a-z-]+}', CONVERTOR_TYPES
+    )
+    assert path_regex == r'^/archive/(?P<year>\d{4})/(?P<slug>[a-z-]+)$'
+    assert path_format == '/archive/{year}/{slug}'
+    assert isinstance(convertors['year'], RegexConvertor)
+    assert convertors['year'].regex == r'\d{4}'
+
+    compiled = re.compile(path_regex)
+    match = compiled.match('/archive/2024/hello-world')
+    assert match is not None
+    assert match.groupdict() == {'year': '2024', 'slug': 'hello-world'}
+    assert compiled.match('/archive/24/hello') is None
+    assert convertors['year'].convert('2024') == '2024'
+
+
+def test_custom_regex_to_string_validates():
+    """Test that a regex convertor only renders values matching its pattern."""
+    convertor = RegexConvertor(r'\d{4}')
+    assert convertor.to_string('2024') == '2024'
+    with pytest.raises(AssertionError, match='does not match'):
+        convertor.to_string('24')
+
+
+@pytest.mark.parametrize('spec', ['(ab', '[a-', 'a{2,1}'])
+def test_invalid_regex_rejected(spec):
+    """Test that a regex that does not compile is a ValueError."""
+    with pytest.raises(ValueError, match='Invalid regex'):
+        compile_path('/items/{item:' + spec + '}', CONVERTOR_TYPES)
+
+
+def test_named_groups_rejected():
+    """Test that a custom regex may not define its own named groups."""
+    with pytest.raises(ValueError, match='must not contain named groups'):
+        compile_path('/items/{item:(?P<inner>[0-9]+)}', CONVERTOR_TYPES)
+
+
+@pytest.mark.parametrize('path', ['/a/{}', '/a/{1abc}', '/a/{name:}', '/a/{name'])
+def test_invalid_placeholders_are_literal(path):
+    """Test that text that is not a valid placeholder is matched literally."""
+    path_regex, path_format, convertors = compile_path(path, CONVERTOR_TYPES)
+    assert convertors == {}
+    assert path_format == path
+    assert re.match(path_regex, path)
+
+
+@pytest.mark.parametrize(
+    ('convertor', 'param_type'),
+    [
+        (StringConvertor(), 'str'),
+        (PathConvertor(), 'path'),
+        (IntegerConvertor(), 'int'),
+        (FloatConvertor(), 'float'),
+        (UUIDConvertor(), 'uuid'),
+        (RegexConvertor('[a-z]+'), 'custom'),
+    ],
+)
+def test_param_type(convertor, param_type):
+    """Test the convertor type name reported for each convertor."""
+    assert convertor.param_type == param_type
+
+
+@pytest.mark.parametrize(
+    'template',
+    [
+        '/users/{id:int}',
+        '/hello/{name}',
+        '/files/{rest:path}',
+        '/prices/{value:float}',
+        '/v1.0/items-{item_id:uuid}',
+        r'/archive/{year:\d{4}}/{month:[0-9]{2}}',
+        '/tags/{tag:[a-z]+(-[a-z]+)*}',
+    ],
+)
+def test_decompile_round_trip(template):
+    """Test that decompile_path recovers the template compile_path started from."""
+    path_regex, _, _ = compile_path(template, CONVERTOR_TYPES)
+    assert decompile_path(path_regex) == template
+
+
+@pytest.mark.parametrize(
+    'path_regex',
+    ['^/users/([0-9]+)$', '^/users/.*$', '^/users/(?P<id>[0-9]+$', '^/a\\'],
+)
+def test_decompile_rejects_other_regexes(path_regex):
+    """Test that regexes compile_path cannot produce are rejected."""
+    with pytest.raises(ValueError):
+        decompile_path(path_regex)
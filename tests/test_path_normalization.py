@@ -0,0 +1,67 @@
+"""Tests for request path normalization in the Rust routing module."""
+
+import pytest
+
+from velithon._velithon import _UnifiedRouteOptimizer, normalize_path
+from velithon.convertors import CONVERTOR_TYPES
+
+
+@pytest.mark.parametrize(
+    ('path', 'expected'),
+    [
+        ('/', '/'),
+        ('', '/'),
+        ('/users/1', '/users/1'),
+        ('//users/../users/1', '/users/1'),
+        ('//a///b//', '/a/b'),
+        ('/a/./b/.', '/a/b'),
+        ('/a/b/..', '/a'),
+        ('/a/b/../../c', '/c'),
+        ('users/1', '/users/1'),
+    ],
+)
+def test_normalize_path(path, expected):
+    """Test slash collapsing and dot segment resolution."""
+    assert normalize_path(path) == expected
+
+
+@pytest.mark.parametrize(
+    'path', ['/../secret', '/../../secret', '/a/../../secret', '/./../secret']
+)
+def test_normalize_path_does_not_escape_root(path):
+    """Test that parent segments never climb above the root."""
+    assert normalize_path(path) == '/secret'
+
+
+def test_normalize_path_keeps_trailing_slash():
+    """Test that the trailing slash can be preserved."""
+    assert normalize_path('//a///b//', strip_trailing_slash=False) == '/a/b/'
+    assert normalize_path('/a/b/..', strip_trailing_slash=False) == '/a/'
+    assert normalize_path('/a/b', strip_trailing_slash=False) == '/a/b'
+    assert normalize_path('/..', strip_trailing_slash=False) == '/'
+
+
+def test_route_optimizer_normalizes_paths():
+    """Test that the unified optimizer matches normalized paths when enabled."""
+    optimizer = _UnifiedRouteOptimizer(normalize_paths=True)
+    optimizer.add_exact_route('/users', 0, ['GET'])
+    optimizer.add_regex_route(
+        '^/users/(?P<user_id>[0-9]+)$',
+        1,
+        ['GET'],
+        {'user_id': CONVERTOR_TYPES['int']},
+    )
+
+    assert optimizer.match_route('//users/', 'GET')[0] == 0
+    route_index, _, params = optimizer.match_route('/users/../users//7', 'GET')
+    assert route_index == 1
+    assert params == {'user_id': 7}
+
+
+def test_route_optimizer_leaves_paths_by_default():
+    """Test that normalization is opt-in."""
+    optimizer = _UnifiedRouteOptimizer()
+    optimizer.add_exact_route('/users', 0, ['GET'])
+
+    assert optimizer.match_route('/users', 'GET')[0] == 0
+    assert optimizer.match_route('//users', 'GET')[0] == -1
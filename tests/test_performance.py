@@ -0,0 +1,366 @@
+"""Tests for the Rust performance monitoring helpers."""
+
+import json
+import re
+import sys
+import time
+
+import pytest
+
+from velithon._velithon import (
+    HotPathDetector,
+    OTelTracer,
+    ResourceSampler,
+    SamplingProfiler,
+    ThroughputCounter,
+)
+
+
+def busy_profiled_function(seconds):
+    """Spin in Python so the profiler has something to sample."""
+    deadline = time.monotonic() + seconds
+    total = 0
+    while time.monotonic() < deadline:
+        total += sum(range(100))
+    return total
+
+
+class TestSamplingProfiler:
+    """Test sampling Python stacks and exporting folded stacks."""
+
+    def test_invalid_arguments(self):
+        """Test that a zero interval or sample limit is rejected."""
+        profiler = SamplingProfiler()
+        with pytest.raises(ValueError, match='interval_ms'):
+            profiler.start(interval_ms=0)
+        with pytest.raises(ValueError, match='max_samples'):
+            profiler.start(max_samples=0)
+        assert not profiler.is_running()
+
+    def test_profiles_busy_function(self):
+        """Test that a busy function shows up in the folded output."""
+        profiler = SamplingProfiler()
+        profiler.start(interval_ms=2)
+        assert profiler.is_running()
+        busy_profiled_function(0.5)
+        collected = profiler.stop()
+        assert not profiler.is_running()
+
+        assert collected > 0
+        folded = profiler.export_folded_stacks()
+        lines = [
+            line for line in folded.splitlines() if 'busy_profiled_function' in line
+        ]
+        assert lines
+        for line in lines:
+            stack, count = line.rsplit(' ', 1)
+            assert int(count) >= 1
+            # Frames run outermost first, so the caller precedes the busy function
+            frames = stack.split(';')
+            names = [frame.split(' (')[0] for frame in frames]
+            busy = names.index('busy_profiled_function')
+            assert names[busy - 1] == 'test_profiles_busy_function'
+            assert f'({__file__}:' in frames[busy]
+
+    def test_stop_keeps_samples_until_cleared(self):
+        """Test that samples survive stop and are discarded by clear."""
+        profiler = SamplingProfiler()
+        profiler.start(interval_ms=2)
+        busy_profiled_function(0.1)
+        collected = profiler.stop()
+        time.sleep(0.05)
+        assert profiler.stop() == collected
+        profiler.clear()
+        assert profiler.stop() == 0
+        assert profiler.export_folded_stacks() == ''
+
+    def test_max_samples_bounds_history(self):
+        """Test that only the most recent max_samples stacks are kept."""
+        profiler = SamplingProfiler()
+        profiler.start(interval_ms=1, max_samples=3)
+        busy_profiled_function(0.2)
+        assert profiler.stop() == 3
+        folded = profiler.export_folded_stacks()
+        assert sum(int(line.rsplit(' ', 1)[1]) for line in folded.splitlines()) == 3
+
+
+@pytest.mark.parametrize('lock_free', [False, True])
+class TestThroughputCounter:
+    """Test event rates in the exact and lock-free bucket modes."""
+
+    def test_rate_and_reset(self, lock_free):
+        """Test the rate within one window and that reset clears the peak too."""
+        counter = ThroughputCounter(window_secs=4, lock_free=lock_free)
+        assert counter.is_lock_free() is lock_free
+        assert counter.get_rate() == 0.0
+
+        for _ in range(10):
+            counter.record()
+        assert counter.get_rate() == 2.5
+        assert counter.get_peak_rate() == 2.5
+
+        counter.reset()
+        assert counter.get_rate() == 0.0
+        assert counter.get_peak_rate() == 0.0
+
+    def test_rate_decays_across_window_boundaries(self, lock_free):
+        """Test that events stop counting once they fall out of the window."""
+        # Buckets are whole seconds since creation, so the sleeps keep every
+        # check 0.2s away from a bucket boundary
+        counter = ThroughputCounter(window_secs=2, lock_free=lock_free)
+        for _ in range(5):
+            counter.record()
+        assert counter.get_rate() == 2.5
+
+        time.sleep(1.2)
+        for _ in range(3):
+            counter.record()
+        assert counter.get_rate() == 4.0
+
+        time.sleep(1.0)
+        # The first five events are now more than two seconds old
+        assert counter.get_rate() == 1.5
+        counter.record()
+        assert counter.get_rate() == 2.0
+
+        time.sleep(2.0)
+        assert counter.get_rate() == 0.0
+        assert counter.get_peak_rate() == 4.0
+
+    def test_invalid_window(self, lock_free):
+        """Test that a zero-second window is rejected."""
+        with pytest.raises(ValueError, match='window_secs'):
+            ThroughputCounter(window_secs=0, lock_free=lock_free)
+
+
+class TestHotPathDetector:
+    """Test counting calls per label."""
+
+    def make_detector(self, **calls):
+        """Detector with each label recorded the given number of times."""
+        detector = HotPathDetector()
+        for label, count in calls.items():
+            for _ in range(count):
+                detector.record(label)
+        return detector
+
+    def test_top_n_ordering(self):
+        """Test that labels are ordered by count, then alphabetically."""
+        detector = self.make_detector(users=5, orders=9, items=5, health=1)
+        assert detector.get_hot_paths(3) == [('orders', 9), ('items', 5), ('users', 5)]
+        assert detector.get_hot_paths(10) == [
+            ('orders', 9),
+            ('items', 5),
+            ('users', 5),
+            ('health', 1),
+        ]
+        assert detector.get_hot_paths(0) == []
+
+    def test_threshold_crossing(self):
+        """Test that a label is reported once it reaches min_calls."""
+        detector = self.make_detector(search=2, home=4)
+        assert detector.get_threshold_paths(3) == [('home', 4)]
+
+        detector.record('search')
+        assert detector.get_threshold_paths(3) == [('home', 4), ('search', 3)]
+        assert detector.get_threshold_paths(5) == []
+        assert detector.get_threshold_paths(0) == [('home', 4), ('search', 3)]
+
+    def test_reset(self):
+        """Test forgetting one label or all of them."""
+        detector = self.make_detector(a=2, b=1)
+        detector.reset('a')
+        detector.reset('missing')
+        assert detector.get_hot_paths(5) == [('b', 1)]
+        detector.reset()
+        assert detector.get_hot_paths(5) == []
+
+    def test_decay(self):
+        """Test that decay scales every count and can drop labels below a threshold."""
+        detector = self.make_detector(a=10, b=3)
+        detector.decay(0.5)
+        assert detector.get_hot_paths(5) == [('a', 5), ('b', 1)]
+        assert detector.get_threshold_paths(2) == [('a', 5)]
+
+        detector.decay(1.0)
+        assert detector.get_hot_paths(5) == [('a', 5), ('b', 1)]
+        for factor in (-0.1, 1.5):
+            with pytest.raises(ValueError, match='factor'):
+                detector.decay(factor)
+
+
+TRACE_ID = '4bf92f3577b34da6a3ce929d0e0e4736'
+PARENT_ID = '00f067aa0ba902b7'
+
+
+class TestTracing:
+    """Test W3C Trace Context spans and OTLP export."""
+
+    def test_root_span(self):
+        """Test that a root span gets fresh ids and a well-formed traceparent."""
+        span = OTelTracer().start_span('request')
+        assert re.fullmatch('[0-9a-f]{32}', span.trace_id)
+        assert re.fullmatch('[0-9a-f]{16}', span.span_id)
+        assert span.parent_span_id is None
+        assert span.name == 'request'
+        assert span.traceparent() == f'00-{span.trace_id}-{span.span_id}-01'
+        assert span.tracestate() == ''
+
+    def test_child_continues_parent_trace(self):
+        """Test that a child span keeps the trace id and points at its parent."""
+        tracer = OTelTracer()
+        parent = tracer.start_span('request')
+        child = tracer.start_span('query', parent.trace_id, parent.span_id)
+        sibling = tracer.start_span('render', parent.trace_id, parent.span_id)
+
+        assert child.trace_id == parent.trace_id
+        assert child.parent_span_id == parent.span_id
+        assert len({parent.span_id, child.span_id, sibling.span_id}) == 3
+
+    def test_traceparent_round_trip(self):
+        """Test that a span's traceparent starts a child span downstream."""
+        upstream = OTelTracer().start_span('client')
+        downstream = OTelTracer().start_span_from_traceparent(
+            'server', upstream.traceparent()
+        )
+        assert downstream.trace_id == upstream.trace_id
+        assert downstream.parent_span_id == upstream.span_id
+
+    @pytest.mark.parametrize(
+        'traceparent',
+        [
+            f'00-{TRACE_ID}-{PARENT_ID}-01',
+            f'00-{TRACE_ID}-{PARENT_ID}-00',
+            f' 00-{TRACE_ID}-{PARENT_ID}-01 ',
+            # Later versions may append fields
+            f'01-{TRACE_ID}-{PARENT_ID}-01-extra',
+        ],
+    )
+    def test_parse_traceparent(self, traceparent):
+        """Test parsing valid traceparent headers."""
+        span = OTelTracer().start_span_from_traceparent('server', traceparent)
+        assert span.trace_id == TRACE_ID
+        assert span.parent_span_id == PARENT_ID
+        assert span.span_id != PARENT_ID
+
+    @pytest.mark.parametrize(
+        'traceparent',
+        [
+            '',
+            'garbage',
+            f'00-{TRACE_ID}-{PARENT_ID}',
+            f'00-{TRACE_ID}-{PARENT_ID}-01-extra',
+            f'ff-{TRACE_ID}-{PARENT_ID}-01',
+            f'0-{TRACE_ID}-{PARENT_ID}-01',
+            f'00-{TRACE_ID.upper()}-{PARENT_ID}-01',
+            f'00-{TRACE_ID[:-1]}-{PARENT_ID}-01',
+            f'00-{"0" * 32}-{PARENT_ID}-01',
+            f'00-{TRACE_ID}-{"0" * 16}-01',
+            f'00-{TRACE_ID}-{PARENT_ID}x-01',
+            f'00-{TRACE_ID}-{PARENT_ID}-1',
+            f'00-{TRACE_ID}-{PARENT_ID}-zz',
+        ],
+    )
+    def test_malformed_traceparent(self, traceparent):
+        """Test that malformed traceparent headers are rejected."""
+        with pytest.raises(ValueError, match='Invalid traceparent'):
+            OTelTracer().start_span_from_traceparent('server', traceparent)
+
+    @pytest.mark.parametrize(
+        ('trace_id', 'span_id', 'message'),
+        [
+            ('abc', None, 'Invalid parent trace id'),
+            ('0' * 32, None, 'Invalid parent trace id'),
+            (TRACE_ID, 'XYZ', 'Invalid parent span id'),
+        ],
+    )
+    def test_invalid_parent_ids(self, trace_id, span_id, message):
+        """Test that start_span validates explicit parent ids."""
+        with pytest.raises(ValueError, match=message):
+            OTelTracer().start_span('child', trace_id, span_id)
+
+    def test_export(self):
+        """Test OTLP export of parent and child spans with status and attributes."""
+        tracer = OTelTracer()
+        parent = tracer.start_span('request')
+        child = tracer.start_span('query', parent.trace_id, parent.span_id)
+        tracer.end_span(child, 'ERROR', {'db': 'users', 'attempt': '1'})
+        tracer.end_span(parent)
+        assert tracer.span_count() == 2
+
+        exported = json.loads(tracer.export_spans_json())
+        assert [span['name'] for span in exported] == ['query', 'request']
+        query, request = exported
+        assert query['traceId'] == request['traceId'] == parent.trace_id
+        assert query['parentSpanId'] == request['spanId'] == parent.span_id
+        assert request['parentSpanId'] == ''
+        assert query['status'] == {'code': 2}
+        assert request['status'] == {'code': 1}
+        assert query['attributes'] == [
+            {'key': 'attempt', 'value': {'stringValue': '1'}},
+            {'key': 'db', 'value': {'stringValue': 'users'}},
+        ]
+        assert int(query['startTimeUnixNano']) <= int(query['endTimeUnixNano'])
+
+        tracer.clear()
+        assert tracer.export_spans_json() == '[]'
+
+    def test_max_spans_and_status(self):
+        """Test that old spans are discarded and unknown statuses rejected."""
+        tracer = OTelTracer(max_spans=2)
+        for name in ('a', 'b', 'c'):
+            tracer.end_span(tracer.start_span(name), 'unset')
+        exported = json.loads(tracer.export_spans_json())
+        assert [span['name'] for span in exported] == ['b', 'c']
+        assert exported[0]['status'] == {'code': 0}
+
+        with pytest.raises(ValueError, match='Invalid span status'):
+            tracer.end_span(tracer.start_span('d'), 'failed')
+        with pytest.raises(ValueError, match='max_spans'):
+            OTelTracer(max_spans=0)
+
+
+class TestResourceSampler:
+    """Test background sampling of process CPU and memory."""
+
+    def test_invalid_arguments(self):
+        """Test that zero sizes and intervals are rejected."""
+        with pytest.raises(ValueError, match='max_samples'):
+            ResourceSampler(max_samples=0)
+        with pytest.raises(ValueError, match='interval_ms'):
+            ResourceSampler().start(0)
+
+    @pytest.mark.skipif(not sys.platform.startswith('linux'), reason='needs /proc')
+    def test_samples_on_linux(self):
+        """Test that samples report a non-zero RSS and are bounded by max_samples."""
+        sampler = ResourceSampler(max_samples=3)
+        assert sampler.available
+        assert sampler.get_latest() is None
+
+        sampler.start(5)
+        assert sampler.is_running()
+        deadline = time.monotonic() + 5
+        while len(sampler.get_history(10)) < 3 and time.monotonic() < deadline:
+            busy_profiled_function(0.01)
+        time.sleep(0.05)
+        sampler.stop()
+        assert not sampler.is_running()
+
+        latest = sampler.get_latest()
+        assert latest['rss_mb'] > 0
+        assert latest['cpu_pct'] >= 0
+        assert latest['timestamp'] > 1_600_000_000_000
+        history = sampler.get_history(10)
+        assert len(history) == 3
+        assert history[-1] == latest
+        assert sampler.get_history(1) == [latest]
+        timestamps = [sample['timestamp'] for sample in history]
+        assert timestamps == sorted(timestamps)
+
+    @pytest.mark.skipif(sys.platform.startswith('linux'), reason='/proc exists')
+    def test_unavailable_without_proc(self):
+        """Test that without /proc the sampler refuses to start."""
+        sampler = ResourceSampler()
+        assert not sampler.available
+        with pytest.raises(RuntimeError, match='/proc/self'):
+            sampler.start(10)
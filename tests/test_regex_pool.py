@@ -0,0 +1,72 @@
+"""Tests for the process-wide pool of compiled route regexes."""
+
+import uuid
+
+import pytest
+
+from velithon._velithon import (
+    Match,
+    _RouteOptimizer,
+    _RoutePatternMatcher,
+    _UnifiedRouteOptimizer,
+    clear_regex_pool,
+    get_regex_pool_size,
+)
+from velithon.convertors import CONVERTOR_TYPES
+
+INT_CONVERTORS = {'id': CONVERTOR_TYPES['int']}
+
+
+def unique_pattern():
+    """Route regex no other test has pooled yet."""
+    return f'^/pool-{uuid.uuid4().hex}/(?P<id>[0-9]+)$'
+
+
+def test_same_pattern_is_compiled_once():
+    """Test that optimizers registering one pattern share a pooled regex."""
+    pattern = unique_pattern()
+    before = get_regex_pool_size()
+
+    first = _UnifiedRouteOptimizer()
+    first.add_regex_route(pattern, 0, ['GET'], INT_CONVERTORS)
+    assert get_regex_pool_size() == before + 1
+
+    second = _UnifiedRouteOptimizer()
+    second.add_regex_route(pattern, 0, ['GET'], INT_CONVERTORS)
+    _RouteOptimizer(pattern, '/pool/{id}', INT_CONVERTORS, ['GET'])
+    _RoutePatternMatcher().add_pattern(pattern, '/pool/{id}', INT_CONVERTORS)
+    assert get_regex_pool_size() == before + 1
+
+    _UnifiedRouteOptimizer().add_regex_route(unique_pattern(), 0, ['GET'], {})
+    assert get_regex_pool_size() == before + 2
+
+
+def test_invalid_pattern_is_not_pooled():
+    """Test that a regex that fails to compile raises and is not pooled."""
+    before = get_regex_pool_size()
+    with pytest.raises(ValueError, match='Invalid regex'):
+        _UnifiedRouteOptimizer().add_regex_route('^/broken/(?P<id>$', 0, ['GET'], {})
+    assert get_regex_pool_size() == before
+
+
+def test_clear_pool_keeps_existing_optimizers_working():
+    """Test that clearing the pool does not affect regexes already in use."""
+    pattern = unique_pattern()
+    path = pattern[1:].replace('(?P<id>[0-9]+)$', '42')
+
+    unified = _UnifiedRouteOptimizer()
+    unified.add_regex_route(pattern, 3, ['GET'], INT_CONVERTORS)
+    route = _RouteOptimizer(pattern, '/pool/{id}', INT_CONVERTORS, ['GET'])
+    matcher = _RoutePatternMatcher()
+    matcher.add_pattern(pattern, '/pool/{id}', INT_CONVERTORS)
+
+    clear_regex_pool()
+    assert get_regex_pool_size() == 0
+
+    assert unified.match_route(path, 'GET') == (3, Match.FULL, {'id': 42})
+    assert route.matches(path, 'GET') == (Match.FULL, {'id': 42})
+    assert matcher.match_path(path) == (0, {'id': 42})
+
+    # New registrations repopulate the pool
+    _UnifiedRouteOptimizer().add_regex_route(pattern, 0, ['GET'], INT_CONVERTORS)
+    assert get_regex_pool_size() == 1
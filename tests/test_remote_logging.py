@@ -0,0 +1,201 @@
+"""Tests for shipping log records to a remote HTTP endpoint."""
+
+import asyncio
+import json
+import threading
+import time
+from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
+
+import pytest
+
+from velithon._velithon import (
+    add_remote_handler,
+    flush_remote,
+    log_info,
+    log_warn,
+    remove_remote_handler,
+)
+from velithon.event import EventChannel
+
+# Long enough that only full batches or flush_remote trigger a send
+NEVER_MS = 60_000
+
+
+class LogCollector(ThreadingHTTPServer):
+    """Local endpoint recording each POSTed batch."""
+
+    def __init__(self):
+        """Listen on a free local port."""
+        super().__init__(('127.0.0.1', 0), LogCollectorHandler)
+        self.batches = []
+        self.status = 200
+
+    @property
+    def url(self):
+        """URL the remote handler should post to."""
+        return f'http://127.0.0.1:{self.server_port}/logs'
+
+    def messages(self):
+        """Messages received so far, in arrival order."""
+        return [record['message'] for batch in self.batches for record in batch]
+
+
+class LogCollectorHandler(BaseHTTPRequestHandler):
+    """Store request bodies and answer with the collector's status."""
+
+    def do_POST(self):
+        """Record one batch."""
+        body = self.rfile.read(int(self.headers['content-length']))
+        if self.server.status == 200:
+            self.server.batches.append(json.loads(body))
+        self.send_response(self.server.status)
+        self.end_headers()
+
+    def log_message(self, format, *args):
+        """Keep test output quiet."""
+
+
+@pytest.fixture
+def collector():
+    """Run a collector for one test and remove the remote handler afterwards."""
+    server = LogCollector()
+    thread = threading.Thread(target=server.serve_forever, daemon=True)
+    thread.start()
+    yield server
+    remove_remote_handler()
+    server.shutdown()
+    server.server_close()
+
+
+def wait_until(predicate, timeout=5.0):
+    """Poll until predicate() is true or the timeout expires."""
+    deadline = time.monotonic() + timeout
+    while not predicate():
+        if time.monotonic() > deadline:
+            raise AssertionError('condition not met before timeout')
+        time.sleep(0.01)
+
+
+def flush_until(collector, count):
+    """Flush until the collector has received `count` records."""
+    wait_until(lambda: flush_remote() >= 0 and len(collector.messages()) >= count)
+
+
+@pytest.mark.parametrize(
+    ('kwargs', 'message'),
+    [
+        ({'url': 'https://logs.example.com'}, 'only supports http://'),
+        ({'url': 'not a url'}, 'Invalid URL'),
+        ({'url': 'http://localhost', 'batch_size': 0}, 'must be greater than 0'),
+        ({'url': 'http://localhost', 'max_buffer_size': 0}, 'must be greater than 0'),
+    ],
+)
+def test_invalid_configuration(kwargs, message):
+    """Test that unsupported URLs and zero sizes are rejected."""
+    with pytest.raises(ValueError, match=message):
+        add_remote_handler(**kwargs)
+
+
+def test_flush_without_handler():
+    """Test that flushing with no remote handler configured raises."""
+    remove_remote_handler()
+    with pytest.raises(RuntimeError, match='No remote log handler configured'):
+        flush_remote()
+
+
+def test_flush_sends_buffered_records(collector):
+    """Test that flush_remote sends a partial batch right away."""
+    add_remote_handler(collector.url, batch_size=10, flush_interval_ms=NEVER_MS)
+    log_info('first', 'tests', 1)
+    log_info('second', 'tests', 2)
+
+    flush_until(collector, 2)
+    assert collector.messages() == ['first', 'second']
+    record = collector.batches[0][0]
+    assert (record['level'], record['module'], record['line']) == ('INFO', 'tests', 1)
+    assert flush_remote() == 0
+
+
+def test_full_batches_are_sent_without_flush(collector):
+    """Test batching: a full batch wakes the shipper, and batches hold batch_size."""
+    add_remote_handler(collector.url, batch_size=2, flush_interval_ms=NEVER_MS)
+    for i in range(5):
+        log_info(f'message {i}', 'tests', i)
+
+    wait_until(lambda: len(collector.messages()) >= 4)
+    flush_until(collector, 5)
+    assert collector.messages() == [f'message {i}' for i in range(5)]
+    assert [len(batch) for batch in collector.batches] == [2, 2, 1]
+
+
+def test_level_filter(collector):
+    """Test that records below the handler's level are not shipped."""
+    add_remote_handler(collector.url, flush_interval_ms=NEVER_MS, level='WARN')
+    log_info('ignored', 'tests', 1)
+    log_warn('shipped', 'tests', 2)
+
+    flush_until(collector, 1)
+    time.sleep(0.05)
+    assert flush_remote() == 0
+    assert collector.messages() == ['shipped']
+
+
+def test_failed_batches_stay_buffered(collector):
+    """Test that records are kept for a retry when the endpoint fails."""
+    collector.status = 500
+    add_remote_handler(collector.url, flush_interval_ms=NEVER_MS)
+    log_info('retried', 'tests', 1)
+
+    def flush_fails():
+        try:
+            flush_remote()
+        except ConnectionError as exc:
+            assert 'endpoint returned 500' in str(exc)
+            return True
+        return False
+
+    wait_until(flush_fails)
+    collector.status = 200
+    flush_until(collector, 1)
+    assert collector.messages() == ['retried']
+
+
+@pytest.mark.asyncio
+@pytest.mark.parametrize('is_async', [False, True])
+async def test_flush_from_event_listener(collector, is_async):
+    """Test that flushing from the runtime's threads does not panic."""
+    add_remote_handler(collector.url, flush_interval_ms=NEVER_MS)
+    log_info('from listener', 'tests', 1)
+    results = []
+
+    def listener(data):
+        # Async listeners are called on a runtime worker to create their coroutine
+        try:
+            results.append(flush_remote())
+        except BaseException as exc:
+            results.append(exc)
+        return asyncio.sleep(0)
+
+    channel = EventChannel()
+    channel.register_listener('flush', listener, is_async, asyncio.get_running_loop())
+    await channel.emit('flush', {})
+
+    deadline = asyncio.get_running_loop().time() + 5
+    while not results and asyncio.get_running_loop().time() < deadline:
+        await asyncio.sleep(0.01)
+    await channel.cleanup()
+
+    assert len(results) == 1
+    assert not isinstance(results[0], BaseException), results[0]
+    await asyncio.to_thread(flush_until, collector, 1)
+    assert collector.messages() == ['from listener']
+
+
+def test_remove_remote_handler(collector):
+    """Test that removing the handler stops shipping."""
+    add_remote_handler(collector.url, flush_interval_ms=NEVER_MS)
+    remove_remote_handler()
+    log_info('dropped', 'tests', 1)
+    with pytest.raises(RuntimeError, match='No remote log handler configured'):
+        flush_remote()
+    assert collector.messages() == []
@@ -0,0 +1,88 @@
+"""Tests for HMAC signing of service-to-service requests."""
+
+import pytest
+
+from velithon._velithon import RequestSigner
+
+HEADERS = [('Content-Type', 'application/json'), ('X-Request-Id', 'abc')]
+BODY = b'{"amount": 10}'
+
+
+@pytest.fixture
+def signer():
+    """Signer with a fixed test secret."""
+    return RequestSigner('test-secret')
+
+
+def test_sign_returns_hex_sha256(signer):
+    """Test that signatures are 64 lowercase hex characters."""
+    signature = signer.sign('POST', '/payments', HEADERS, BODY)
+    assert len(signature) == 64
+    assert all(c in '0123456789abcdef' for c in signature)
+    assert signer.verify(signature, 'POST', '/payments', HEADERS, BODY)
+
+
+def test_signature_ignores_header_order_and_name_case(signer):
+    """Test that headers are canonicalized before signing."""
+    signature = signer.sign('POST', '/payments', HEADERS, BODY)
+    reordered = [('x-request-id', 'abc'), ('CONTENT-TYPE', ' application/json ')]
+    assert signer.sign('post', '/payments', reordered, BODY) == signature
+    assert signer.verify(signature, 'POST', '/payments', reordered, BODY)
+
+
+@pytest.mark.parametrize(
+    ('method', 'path', 'headers', 'body'),
+    [
+        ('POST', '/payments', HEADERS, b'{"amount": 1000}'),
+        ('POST', '/refunds', HEADERS, BODY),
+        ('PUT', '/payments', HEADERS, BODY),
+        ('POST', '/payments', [('X-Request-Id', 'abc')], BODY),
+        ('POST', '/payments', [*HEADERS[:1], ('X-Request-Id', 'abd')], BODY),
+    ],
+)
+def test_tampered_request_fails_verification(signer, method, path, headers, body):
+    """Test that any change to the signed request invalidates the signature."""
+    signature = signer.sign('POST', '/payments', HEADERS, BODY)
+    assert not signer.verify(signature, method, path, headers, body)
+
+
+def test_other_secret_fails_verification(signer):
+    """Test that a signature from another secret does not verify."""
+    signature = RequestSigner('other-secret').sign('GET', '/', [], b'')
+    assert not signer.verify(signature, 'GET', '/', [], b'')
+
+
+@pytest.mark.parametrize(
+    'signature', ['', 'abc', 'zz' * 32, '+f' * 32, 'é' * 64, '00' * 31, '00' * 33]
+)
+def test_malformed_signature_returns_false(signer, signature):
+    """Test that malformed, odd-length or wrong-length hex never verifies."""
+    assert signer.verify(signature, 'GET', '/', [], b'') is False
+
+
+def test_sign_prefixed_hex_does_not_verify(signer):
+    """Test that '+f' is not accepted as an alternative spelling of '0f'."""
+    signature = signer.sign('DELETE', '/payments/1', [], b'')
+    pairs = [signature[i : i + 2] for i in range(0, len(signature), 2)]
+    index = next(i for i, pair in enumerate(pairs) if pair[0] == '0')
+    pairs[index] = '+' + pairs[index][1]
+    assert not signer.verify(''.join(pairs), 'DELETE', '/payments/1', [], b'')
+
+
+def test_uppercase_hex_signature_verifies(signer):
+    """Test that hex decoding is case-insensitive."""
+    signature = signer.sign('GET', '/', [], b'')
+    assert signer.verify(signature.upper(), 'GET', '/', [], b'')
+
+
+def test_empty_secret_rejected():
+    """Test that an empty secret raises ValueError."""
+    with pytest.raises(ValueError, match='must not be empty'):
+        RequestSigner('')
+
+
+@pytest.mark.parametrize('algorithm', ['HMAC-SHA1', 'HMAC-SHA512', 'none'])
+def test_unknown_algorithm_rejected(algorithm):
+    """Test that only HMAC-SHA256 is accepted."""
+    with pytest.raises(ValueError, match='Unsupported signing algorithm'):
+        RequestSigner('secret', algorithm)
@@ -0,0 +1,124 @@
+"""Tests for the static prefix index of the unified route optimizer."""
+
+import pytest
+
+from velithon._velithon import _UnifiedRouteOptimizer
+from velithon.convertors import CONVERTOR_TYPES
+
+
+def make_optimizer(*patterns):
+    """Register regex routes in order; route indexes follow registration order."""
+    optimizer = _UnifiedRouteOptimizer()
+    for route_index, pattern in enumerate(patterns):
+        optimizer.add_regex_route(pattern, route_index, ['GET'], {})
+    return optimizer
+
+
+def matched_index(optimizer, path):
+    """Route index matched for a GET request, or -1."""
+    return optimizer.match_route(path, 'GET')[0]
+
+
+def test_first_registered_route_wins_across_prefixes():
+    """Test that a broad route registered first still beats a specific one."""
+    broad_first = make_optimizer('^/(?P<rest>.*)$', '^/api/users/(?P<id>[0-9]+)$')
+    assert matched_index(broad_first, '/api/users/5') == 0
+
+    specific_first = make_optimizer('^/api/users/(?P<id>[0-9]+)$', '^/(?P<rest>.*)$')
+    assert matched_index(specific_first, '/api/users/5') == 0
+    assert matched_index(specific_first, '/api/other') == 1
+
+
+def test_registration_order_within_longer_prefixes():
+    """Test ordering when the earlier route has the longer prefix."""
+    optimizer = make_optimizer(
+        '^/api/v1/items/(?P<id>[0-9]+)$',
+        '^/api/(?P<version>[^/]+)/items/(?P<id>[0-9]+)$',
+    )
+    assert matched_index(optimizer, '/api/v1/items/3') == 0
+    assert matched_index(optimizer, '/api/v2/items/3') == 1
+
+
+@pytest.mark.parametrize(
+    ('pattern', 'path'),
+    [
+        ('^/items/?$', '/items'),
+        ('^/items/?$', '/items/'),
+        ('^/colou?r$', '/color'),
+        ('^/colou?r$', '/colour'),
+        ('^/ab*c$', '/ac'),
+        ('^/ab{0,2}c$', '/ac'),
+        ('^/ab+c$', '/abbc'),
+    ],
+)
+def test_quantified_characters_are_optional(pattern, path):
+    """Test that a quantified character is not treated as a required prefix."""
+    assert matched_index(make_optimizer(pattern), path) == 0
+
+
+@pytest.mark.parametrize(
+    ('pattern', 'path', 'expected'),
+    [
+        (r'^/files/report\-(?P<year>[0-9]+)\.csv$', '/files/report-2024.csv', 0),
+        (r'^/v1\.0/status$', '/v1.0/status', 0),
+        (r'^/v1\.0/status$', '/v1x0/status', -1),
+        (r'^/a\-?b$', '/ab', 0),
+        (r'^/a\-?b$', '/a-b', 0),
+        (r'^/tag/\#(?P<name>[a-z]+)$', '/tag/#rust', 0),
+    ],
+)
+def test_escaped_literals(pattern, path, expected):
+    """Test that escaped punctuation is indexed as its literal character."""
+    assert matched_index(make_optimizer(pattern), path) == expected
+
+
+def test_escaped_character_class_ends_prefix():
+    """Test that class escapes such as \\d are not indexed as literals."""
+    optimizer = make_optimizer(r'^/v\d/status$')
+    assert matched_index(optimizer, '/v2/status') == 0
+    assert optimizer.get_index_stats()['prefix_groups'] == 1
+
+
+def test_top_level_alternation():
+    """Test that top-level alternation is not indexed under its first branch."""
+    optimizer = make_optimizer('^/cats$|^/dogs$', '^/(?:birds|fish)$')
+    assert matched_index(optimizer, '/cats') == 0
+    assert matched_index(optimizer, '/dogs') == 0
+    assert matched_index(optimizer, '/fish') == 1
+
+
+def test_unanchored_pattern_matches_anywhere():
+    """Test that unanchored patterns are evaluated for every path."""
+    optimizer = make_optimizer('/legacy/(?P<id>[0-9]+)')
+    assert matched_index(optimizer, '/legacy/5') == 0
+    assert matched_index(optimizer, '/old/legacy/5') == 0
+
+
+def test_non_matching_prefix_is_skipped():
+    """Test that routes are only matched when the path has their prefix."""
+    optimizer = _UnifiedRouteOptimizer()
+    optimizer.add_regex_route(
+        '^/users/(?P<id>[0-9]+)$', 0, ['GET'], {'id': CONVERTOR_TYPES['int']}
+    )
+    optimizer.add_regex_route('^/posts/(?P<slug>[^/]+)$', 1, ['GET'], {})
+
+    assert optimizer.match_route('/users/7', 'GET')[2] == {'id': 7}
+    assert matched_index(optimizer, '/posts/hello') == 1
+    assert matched_index(optimizer, '/comments/1') == -1
+    assert matched_index(optimizer, '/user') == -1
+
+
+def test_index_stats():
+    """Test prefix group statistics and that clear_all resets them."""
+    optimizer = make_optimizer(
+        '^/users/(?P<id>[0-9]+)$',
+        '^/users/(?P<id>[0-9]+)/posts$',
+        '^/users/(?P<id>[0-9]+)/followers$',
+        '^/posts/(?P<slug>[^/]+)$',
+        '^/cats$|^/dogs$',
+    )
+    assert optimizer.get_index_stats() == {'prefix_groups': 3, 'largest_group': 3}
+
+    optimizer.clear_all()
+    assert optimizer.get_index_stats() == {'prefix_groups': 0, 'largest_group': 0}
+    assert matched_index(optimizer, '/users/1') == -1
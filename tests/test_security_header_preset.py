@@ -0,0 +1,111 @@
+"""Tests for security header presets."""
+
+import pytest
+
+from velithon._velithon import SecurityHeaderPreset
+
+STRICT_CSP = (
+    "default-src 'none'; script-src 'self'; style-src 'self'; img-src 'self'; "
+    "connect-src 'self'; font-src 'self'; base-uri 'none'; form-action 'self'; "
+    "frame-ancestors 'none'"
+)
+
+STANDARD_HEADERS = [
+    ('Strict-Transport-Security', 'max-age=31536000; includeSubDomains'),
+    ('X-Content-Type-Options', 'nosniff'),
+    ('X-Frame-Options', 'SAMEORIGIN'),
+    ('Content-Security-Policy', "default-src 'self'; frame-ancestors 'self'"),
+    ('Referrer-Policy', 'strict-origin-when-cross-origin'),
+]
+
+
+def test_strict():
+    """Test the exact headers of the strict preset."""
+    assert SecurityHeaderPreset.strict().build() == [
+        ('Strict-Transport-Security', 'max-age=63072000; includeSubDomains; preload'),
+        ('X-Content-Type-Options', 'nosniff'),
+        ('X-Frame-Options', 'DENY'),
+        ('Content-Security-Policy', STRICT_CSP),
+        ('Referrer-Policy', 'no-referrer'),
+    ]
+
+
+def test_standard():
+    """Test the exact headers of the standard preset and the default constructor."""
+    assert SecurityHeaderPreset.standard().build() == STANDARD_HEADERS
+    assert SecurityHeaderPreset().build() == STANDARD_HEADERS
+
+
+def test_permissive():
+    """Test that the permissive preset only sets nosniff and Referrer-Policy."""
+    assert SecurityHeaderPreset.permissive().build() == [
+        ('X-Content-Type-Options', 'nosniff'),
+        ('Referrer-Policy', 'strict-origin-when-cross-origin'),
+    ]
+
+
+def test_overrides_replace_preset_values():
+    """Test that overrides change only their own header and keep preset flags."""
+    headers = dict(
+        SecurityHeaderPreset.strict()
+        .hsts_max_age(0)
+        .csp("default-src 'self'")
+        .frame_options('sameorigin')
+        .referrer_policy('Same-Origin')
+        .build()
+    )
+    assert headers == {
+        'Strict-Transport-Security': 'max-age=0; includeSubDomains; preload',
+        'X-Content-Type-Options': 'nosniff',
+        'X-Frame-Options': 'SAMEORIGIN',
+        'Content-Security-Policy': "default-src 'self'",
+        'Referrer-Policy': 'same-origin',
+    }
+
+
+def test_overrides_add_headers_to_permissive():
+    """Test that overrides add headers the preset leaves out, in the usual order."""
+    preset = SecurityHeaderPreset.permissive().csp('img-src *').hsts_max_age(60)
+    headers = preset.build()
+    assert headers == [
+        ('Strict-Transport-Security', 'max-age=60'),
+        ('X-Content-Type-Options', 'nosniff'),
+        ('Content-Security-Policy', 'img-src *'),
+        ('Referrer-Policy', 'strict-origin-when-cross-origin'),
+    ]
+
+
+@pytest.mark.parametrize(
+    ('method', 'value', 'message'),
+    [
+        ('frame_options', 'ALLOW-FROM https://a.example', 'Invalid X-Frame-Options'),
+        ('referrer_policy', 'everywhere', 'Invalid Referrer-Policy'),
+        ('csp', "default-src 'self'\r\nX-Injected: 1", 'control characters'),
+    ],
+)
+def test_invalid_overrides(method, value, message):
+    """Test that invalid override values are rejected."""
+    with pytest.raises(ValueError, match=message):
+        getattr(SecurityHeaderPreset(), method)(value)
+
+
+def test_inject_keeps_existing_values():
+    """Test that headers the response already sets are not overwritten."""
+    response_headers = [
+        ('content-type', 'text/html'),
+        ('x-frame-options', 'DENY'),
+        ('Content-Security-Policy', "default-src 'none'"),
+    ]
+    merged = SecurityHeaderPreset.standard().inject_into_response(response_headers)
+    assert merged == [
+        *response_headers,
+        ('Strict-Transport-Security', 'max-age=31536000; includeSubDomains'),
+        ('X-Content-Type-Options', 'nosniff'),
+        ('Referrer-Policy', 'strict-origin-when-cross-origin'),
+    ]
+
+
+def test_inject_into_empty_response():
+    """Test that every preset header is added to a response without any."""
+    preset = SecurityHeaderPreset.strict()
+    assert preset.inject_into_response([]) == preset.build()
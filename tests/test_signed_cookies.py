@@ -0,0 +1,103 @@
+"""Tests for HMAC-signed cookie helpers."""
+
+import pytest
+
+from velithon._velithon import parse_signed_cookies, sign_cookie, verify_cookie
+
+SECRET = 'cookie-secret'
+
+
+def test_sign_and_verify():
+    """Test that a signed value verifies back to the original value."""
+    signed = sign_cookie('session', 'user-42', SECRET)
+    value, signature = signed.rsplit('.', 1)
+    assert value == 'user-42'
+    assert len(signature) == 43
+    assert verify_cookie('session', signed, SECRET) == 'user-42'
+
+
+def test_value_containing_dots():
+    """Test that only the last dot separates the signature."""
+    signed = sign_cookie('token', 'a.b.c', SECRET)
+    assert verify_cookie('token', signed, SECRET) == 'a.b.c'
+
+
+@pytest.mark.parametrize(
+    'tamper',
+    [
+        lambda signed: 'user-43' + signed[len('user-42') :],
+        lambda signed: signed[:-1] + ('A' if signed[-1] != 'A' else 'B'),
+        lambda signed: signed.rsplit('.', 1)[0],
+        lambda signed: signed + '.extra',
+        lambda signed: signed.rsplit('.', 1)[0] + '.not*base64',
+    ],
+)
+def test_tampered_value_rejected(tamper):
+    """Test that a modified value or signature raises ValueError."""
+    signed = sign_cookie('session', 'user-42', SECRET)
+    with pytest.raises(ValueError, match="Invalid signature for cookie 'session'"):
+        verify_cookie('session', tamper(signed), SECRET)
+
+
+def test_wrong_cookie_name_rejected():
+    """Test that the cookie name is part of the signature."""
+    signed = sign_cookie('session', 'admin', SECRET)
+    with pytest.raises(ValueError, match="Invalid signature for cookie 'role'"):
+        verify_cookie('role', signed, SECRET)
+
+
+def test_wrong_secret_rejected():
+    """Test that a value signed with another secret does not verify."""
+    signed = sign_cookie('session', 'user-42', 'other-secret')
+    with pytest.raises(ValueError, match='Invalid signature'):
+        verify_cookie('session', signed, SECRET)
+
+
+def test_empty_secret_rejected():
+    """Test that every helper refuses an empty secret."""
+    with pytest.raises(ValueError, match='must not be empty'):
+        sign_cookie('session', 'x', '')
+    with pytest.raises(ValueError, match='must not be empty'):
+        verify_cookie('session', 'x.' + 'A' * 43, '')
+    with pytest.raises(ValueError, match='must not be empty'):
+        parse_signed_cookies('session=x', '')
+
+
+def test_parse_signed_cookies():
+    """Test verified, failed, quoted and unsigned cookies in one header."""
+    session = sign_cookie('session', 'user-42', SECRET)
+    prefs = sign_cookie('prefs', 'dark mode, "wide"', SECRET)
+    quoted_prefs = '"' + prefs.replace('\\', '\\\\').replace('"', '\\"') + '"'
+    header = '; '.join(
+        [
+            f'session={session}',
+            f'prefs={quoted_prefs}',
+            f'stolen={session}',
+            'forged=value.' + 'A' * 43,
+            'theme=dark',
+            'version=1.2',
+            'malformed',
+        ]
+    )
+
+    cookies = parse_signed_cookies(header, SECRET)
+
+    assert cookies == {
+        'session': 'user-42',
+        'prefs': 'dark mode, "wide"',
+        '_invalid': ['stolen', 'forged'],
+    }
+
+
+def test_parse_signed_cookies_empty_header():
+    """Test that an empty header gives an empty failure list."""
+    assert parse_signed_cookies('', SECRET) == {'_invalid': []}
+
+
+def test_invalid_cookie_name_is_reserved():
+    """Test that a real cookie named _invalid cannot clash with the failure list."""
+    with pytest.raises(ValueError, match="'_invalid' is reserved"):
+        sign_cookie('_invalid', 'x', SECRET)
+
+    header = '_invalid=x.' + 'A' * 43 + '; bad=y.' + 'A' * 43
+    assert parse_signed_cookies(header, SECRET) == {'_invalid': ['bad']}
@@ -0,0 +1,76 @@
+"""Tests for building, parsing and merging Vary headers."""
+
+import pytest
+
+from velithon._velithon import (
+    build_vary_header,
+    merge_vary_headers,
+    parse_vary_header,
+    should_vary_on,
+)
+
+
+def test_build_drops_duplicates_keeping_first_spelling():
+    """Test that names differing only in case are written once."""
+    names = ['Accept-Encoding', 'origin', 'accept-encoding', ' Origin ', '', 'Cookie']
+    assert build_vary_header(names) == 'Accept-Encoding, origin, Cookie'
+    assert build_vary_header([]) == ''
+
+
+def test_build_star():
+    """Test that `*` replaces every other name."""
+    assert build_vary_header(['Accept', '*', 'Origin']) == '*'
+
+
+def test_parse():
+    """Test that names are split, trimmed, lowercased and deduplicated."""
+    vary = ' Accept-Encoding ,ORIGIN,, accept-encoding , Cookie '
+    assert parse_vary_header(vary) == ['accept-encoding', 'origin', 'cookie']
+    assert parse_vary_header('') == []
+    assert parse_vary_header('*') == ['*']
+
+
+@pytest.mark.parametrize(
+    ('a', 'b', 'expected'),
+    [
+        ('Accept-Encoding', 'Origin', 'Accept-Encoding, Origin'),
+        (
+            'Accept-Encoding, Origin',
+            'origin, Cookie',
+            'Accept-Encoding, Origin, Cookie',
+        ),
+        ('ACCEPT', 'accept', 'ACCEPT'),
+        ('', 'Origin', 'Origin'),
+        ('Origin', '', 'Origin'),
+        ('', '', ''),
+        ('Accept, *', 'Origin', '*'),
+        ('Accept', ' * ', '*'),
+    ],
+)
+def test_merge(a, b, expected):
+    """Test merging without duplicates and with `*` taking over."""
+    assert merge_vary_headers(a, b) == expected
+
+
+def test_merge_is_idempotent():
+    """Test that merging a value into itself leaves it unchanged."""
+    vary = 'Accept-Encoding, Origin'
+    assert merge_vary_headers(vary, vary) == vary
+    assert merge_vary_headers(merge_vary_headers(vary, 'origin'), vary) == vary
+
+
+@pytest.mark.parametrize(
+    ('vary', 'request_headers', 'expected'),
+    [
+        ('Accept-Encoding', ['accept-encoding', 'Origin'], ['accept-encoding']),
+        ('accept-encoding, ORIGIN', ['Origin', 'Accept'], ['Origin']),
+        ('ACCEPT', [' accept '], [' accept ']),
+        ('Cookie', ['Accept', 'Origin'], []),
+        ('', ['Accept'], []),
+        ('*', ['Accept', 'Origin'], ['Accept', 'Origin']),
+        ('Origin, *', [], []),
+    ],
+)
+def test_should_vary_on(vary, request_headers, expected):
+    """Test case-insensitive matching against request headers and `*`."""
+    assert should_vary_on(vary, request_headers) == expected
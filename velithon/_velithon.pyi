@@ -5,6 +5,7 @@ import uuid
 # Block Convertor class for request path parameters.
 class Convertor:
     regex: str
+    param_type: str  # built-in type name, or 'custom'
 
     def convert(self, value: str) -> typing.Any: ...
     def to_string(self, value: typing.Any) -> str: ...
@@ -41,6 +42,13 @@ class UUIDConvertor(Convertor):
     def convert(self, value: str) -> uuid.UUID: ...
     def to_string(self, value: uuid.UUID) -> str: ...
 
+class RegexConvertor(Convertor):
+    """Convertor for `{name:regex}` parameters with a custom regex constraint."""
+
+    def __init__(self, regex: str) -> None: ...
+    def convert(self, value: str) -> str: ...
+    def to_string(self, value: str) -> str: ...
+
 def compile_path(
     path: str, convertor_types: dict[str, Convertor]
 ) -> tuple[str, str, dict[str, Convertor]]:
@@ -48,10 +56,28 @@ def compile_path(
     # The implementation is not provided in the original code snippet.
     ...
 
+def decompile_path(regex_pattern: str) -> str:
+    """Recover the path template from a regex produced by `compile_path`."""
+    ...
+
 # Block for Dependency Injection and caching of signatures.
 def di_cached_signature(func: typing.Callable) -> typing.Any:
     pass
 
+def di_cached_signature_with_metadata(func: typing.Callable) -> dict[str, typing.Any]:
+    """Get {'signature', 'is_async', 'param_count'} for a function."""
+    ...
+
+def is_async_callable(func: typing.Any) -> bool:
+    """Check whether calling `func` produces a coroutine."""
+    ...
+
+def di_call_factory(
+    func: typing.Callable, args: list[typing.Any], is_async: bool = False
+) -> typing.Any:
+    """Call a factory; async factories return a coroutine for the caller to await."""
+    ...
+
 class Provide:
     service: typing.Any
 
@@ -192,6 +218,21 @@ def log_critical_with_extra(
     extra: dict[str, typing.Any],
 ) -> None: ...
 def is_enabled_for(level: str) -> bool: ...
+def add_remote_handler(
+    url: str,
+    batch_size: int = 100,
+    flush_interval_ms: int = 1000,
+    max_buffer_size: int = 10000,
+    level: str = 'INFO',
+) -> None:
+    """Ship log records to an http:// endpoint as batched JSON arrays."""
+    ...
+def flush_remote() -> int:
+    """Send all buffered records to the remote endpoint and return the count sent."""
+    ...
+def remove_remote_handler() -> None:
+    """Stop shipping logs remotely, discarding buffered records."""
+    ...
 
 # Block for Background tasks management.
 class BackgroundTask:
@@ -283,6 +324,42 @@ class _RouterOptimizer:
         """Get cache statistics for the router optimizer."""
         ...
 
+class _UnifiedRouteOptimizer:
+    def __init__(
+        self, max_cache_size: int = 2048, normalize_paths: bool = False
+    ) -> None: ...
+    def add_exact_route(
+        self, path: str, route_index: int, methods: list[str]
+    ) -> None:
+        """Add a route without path parameters."""
+        ...
+    def add_regex_route(
+        self,
+        path_regex: str,
+        route_index: int,
+        methods: list[str],
+        param_convertors: dict[str, Convertor],
+    ) -> None:
+        """Add a parameterized route."""
+        ...
+    def match_route(
+        self, path: str, method: str
+    ) -> tuple[int, Match, dict[str, typing.Any] | None]:
+        """Match a path, returning (route_index, match, params); index is -1 on no match."""
+        ...
+    def cache_stats(self) -> tuple[int, int, int, int]:
+        """Get (exact routes, regex routes, cache size, max cache size)."""
+        ...
+    def get_index_stats(self) -> dict[str, int]:
+        """Get prefix index statistics: 'prefix_groups' and 'largest_group'."""
+        ...
+    def clear_all(self) -> None:
+        """Clear all routes and caches."""
+        ...
+    def clear_cache(self) -> None:
+        """Clear the match cache, keeping routes."""
+        ...
+
 class _RoutePatternMatcher:
     patterns: list[tuple[str, str, dict[str, Convertor]]]
     extrac_paths: dict[str, int]  # path:method -> route index
@@ -308,6 +385,24 @@ class _RoutePatternMatcher:
         """Clear all patterns in the matcher."""
         ...
 
+def normalize_path(path: str, strip_trailing_slash: bool = True) -> str:
+    """Collapse repeated slashes and resolve dot segments without leaving the root."""
+    ...
+def build_url(
+    pattern: str,
+    params: dict[str, typing.Any],
+    convertor_types: dict[str, Convertor] | None = None,
+) -> str:
+    """Build a URL by substituting validated, percent-encoded values into a route pattern."""
+    ...
+def get_regex_pool_size() -> int:
+    """Get the number of compiled route regexes shared across optimizers."""
+    ...
+
+def clear_regex_pool() -> None:
+    """Drop all pooled route regexes."""
+    ...
+
 # Proxy classes
 class ProxyClient:
     """High-performance HTTP proxy client with circuit breaker pattern."""
@@ -415,6 +510,13 @@ class RustEventChannel:
 
     buffer_size: int = 1000
 
+    def __init__(
+        self,
+        buffer_size: int = 1000,
+        backpressure_strategy: str = 'block',
+        record_history: bool = False,
+        max_history_size: int = 1000,
+    ) -> None: ...
     def register_listener(
         self,
         event_name: str,
@@ -427,6 +529,37 @@ class RustEventChannel:
     async def emit(self, event_name: str, data: dict) -> None:
         """Emit an event with the provided data."""
         ...
+    def get_channel_depth(self, event_name: str) -> int:
+        """Get the number of queued events not yet dispatched."""
+        ...
+    def get_dropped_count(self, event_name: str) -> int:
+        """Get the number of events dropped because the channel was full."""
+        ...
+    def register_event_schema(
+        self, event_name: str, schema: dict[str, typing.Any]
+    ) -> None:
+        """Register a JSON schema that emitted data must satisfy."""
+        ...
+    def validate_event(self, event_name: str, data: dict) -> list[str]:
+        """Validate data against the event schema without emitting it."""
+        ...
+    def get_rejected_count(self, event_name: str) -> int:
+        """Get the number of events rejected by schema validation."""
+        ...
+    def replay(
+        self,
+        event_name: str | None = None,
+        since: float | None = None,
+        limit: int | None = None,
+    ) -> int:
+        """Re-emit recorded events to current listeners and return how many were sent."""
+        ...
+    def get_history(self, event_name: str | None = None) -> list[dict[str, typing.Any]]:
+        """Get recorded events as {'event_name', 'data', 'timestamp'} dicts."""
+        ...
+    def clear_history(self, event_name: str | None = None) -> None:
+        """Remove recorded events for one event name, or all of them."""
+        ...
     async def cleanup(self) -> None:
         """Clean up resources and close the event channel."""
         ...
@@ -440,3 +573,296 @@ def header_init(
 ) -> tuple[str, str]:
     """Initialize headers for the response."""
     ...
+
+# Block for HTTP header builders and helpers.
+class CookieBuilder:
+    """Builder for Set-Cookie header values."""
+
+    def __init__(self) -> None: ...
+    def name(self, n: str) -> CookieBuilder: ...
+    def value(self, v: str) -> CookieBuilder: ...
+    def domain(self, d: str) -> CookieBuilder: ...
+    def path(self, p: str) -> CookieBuilder: ...
+    def max_age(self, seconds: int) -> CookieBuilder: ...
+    def expires(self, timestamp: int) -> CookieBuilder: ...
+    def secure(self) -> CookieBuilder: ...
+    def http_only(self) -> CookieBuilder: ...
+    def same_site(self, policy: str) -> CookieBuilder: ...
+    def build(self) -> str:
+        """Build the Set-Cookie header value."""
+        ...
+
+def sign_cookie(name: str, value: str, secret: str) -> str:
+    """Sign a cookie value as `value.signature` with HMAC-SHA256.
+
+    The name '_invalid' is reserved by `parse_signed_cookies` and raises ValueError.
+    """
+    ...
+def verify_cookie(name: str, raw_value: str, secret: str) -> str:
+    """Verify a signed cookie value and return the original value."""
+    ...
+def parse_signed_cookies(cookie_header: str, secret: str) -> dict[str, typing.Any]:
+    """Verify signed cookies in a Cookie header; failures are listed under '_invalid'.
+
+    Unsigned cookies and any cookie named '_invalid' are skipped.
+    """
+    ...
+
+class SecurityHeaderPreset:
+    """Security response headers from a strict, standard or permissive preset."""
+
+    def __init__(self) -> None:
+        """Create the standard preset."""
+        ...
+    @staticmethod
+    def strict() -> SecurityHeaderPreset: ...
+    @staticmethod
+    def standard() -> SecurityHeaderPreset: ...
+    @staticmethod
+    def permissive() -> SecurityHeaderPreset: ...
+    def hsts_max_age(self, seconds: int) -> SecurityHeaderPreset: ...
+    def csp(self, policy: str) -> SecurityHeaderPreset: ...
+    def frame_options(self, mode: str) -> SecurityHeaderPreset: ...
+    def referrer_policy(self, policy: str) -> SecurityHeaderPreset: ...
+    def build(self) -> list[tuple[str, str]]:
+        """Build the header name/value pairs."""
+        ...
+    def inject_into_response(
+        self, response_headers: list[tuple[str, str]]
+    ) -> list[tuple[str, str]]:
+        """Add missing security headers without overwriting existing ones."""
+        ...
+
+def build_vary_header(headers: list[str]) -> str:
+    """Build a Vary header value without case-insensitive duplicates."""
+    ...
+def parse_vary_header(vary: str) -> list[str]:
+    """Split a Vary header value into lowercase header names."""
+    ...
+def merge_vary_headers(a: str, b: str) -> str:
+    """Merge two Vary header values."""
+    ...
+def should_vary_on(vary: str, request_headers: list[str]) -> list[str]:
+    """Return the request headers that the Vary directive applies to."""
+    ...
+def parse_http_date(date_str: str) -> int:
+    """Parse an HTTP-date (IMF-fixdate, RFC 850 or asctime) into a Unix timestamp."""
+    ...
+def format_http_date(timestamp: int) -> str:
+    """Format a Unix timestamp as an IMF-fixdate."""
+    ...
+def is_not_modified(last_modified: int, if_modified_since: str) -> bool:
+    """Check whether a conditional GET can be answered with 304 Not Modified."""
+    ...
+def split_http2_headers(
+    headers: list[tuple[str, str]],
+) -> tuple[dict[str, str], dict[str, str]]:
+    """Split HTTP/2 headers into (pseudo-headers, regular headers)."""
+    ...
+def validate_http2_pseudo_headers(pseudo_headers: dict[str, str]) -> list[str]:
+    """Validate request or response pseudo-headers and return any problems."""
+    ...
+def build_http2_request_headers(
+    method: str,
+    path: str,
+    authority: str,
+    regular_headers: dict[str, str],
+    scheme: str = 'https',
+) -> list[tuple[str, str]]:
+    """Build an HTTP/2 request header list with pseudo-headers first."""
+    ...
+
+# Block for request signing between services.
+class RequestSigner:
+    """HMAC-SHA256 signer for service-to-service requests."""
+
+    def __init__(self, secret: str, algorithm: str = 'HMAC-SHA256') -> None: ...
+    def sign(
+        self, method: str, path: str, headers: list[tuple[str, str]], body: bytes
+    ) -> str:
+        """Sign the canonical form of a request and return a hex signature."""
+        ...
+    def verify(
+        self,
+        signature: str,
+        method: str,
+        path: str,
+        headers: list[tuple[str, str]],
+        body: bytes,
+    ) -> bool:
+        """Verify a hex signature using constant-time comparison."""
+        ...
+
+# Block for performance monitoring.
+class ResourceSampler:
+    """Background sampler of process CPU usage and resident memory (Linux)."""
+
+    available: bool
+    """Whether the process statistics the sampler reads exist on this platform."""
+
+    def __init__(self, max_samples: int = 1000) -> None: ...
+    def start(self, interval_ms: int) -> None:
+        """Start sampling every `interval_ms` milliseconds.
+
+        Raises RuntimeError when `available` is False.
+        """
+        ...
+    def stop(self) -> None:
+        """Stop sampling."""
+        ...
+    def is_running(self) -> bool: ...
+    def get_latest(self) -> dict[str, float] | None:
+        """Get the latest sample as {'cpu_pct', 'rss_mb', 'timestamp'}."""
+        ...
+    def get_history(self, n: int) -> list[dict[str, float]]:
+        """Get the last `n` samples, oldest first."""
+        ...
+
+class SpanContext:
+    """Span identifiers in W3C Trace Context form."""
+
+    trace_id: str
+    span_id: str
+    parent_span_id: str | None
+    name: str
+
+    def traceparent(self) -> str:
+        """Get the `traceparent` header value for this span."""
+        ...
+    def tracestate(self) -> str:
+        """Get the `tracestate` header value for this span."""
+        ...
+
+class OTelTracer:
+    """Creates OpenTelemetry-compatible spans and exports completed ones as OTLP JSON."""
+
+    def __init__(self, max_spans: int = 10000) -> None: ...
+    def start_span(
+        self,
+        name: str,
+        parent_trace_id: str | None = None,
+        parent_span_id: str | None = None,
+    ) -> SpanContext:
+        """Start a span, continuing the parent trace if given."""
+        ...
+    def start_span_from_traceparent(self, name: str, traceparent: str) -> SpanContext:
+        """Start a span continuing the trace in a `traceparent` header.
+
+        Raises ValueError if the header is malformed.
+        """
+        ...
+    def end_span(
+        self,
+        ctx: SpanContext,
+        status: str = 'ok',
+        attributes: dict[str, str] | None = None,
+    ) -> None:
+        """Record a finished span with status 'ok', 'error' or 'unset'."""
+        ...
+    def export_spans_json(self) -> str:
+        """Export all completed spans as an OTLP JSON array."""
+        ...
+    def span_count(self) -> int: ...
+    def clear(self) -> None:
+        """Drop all completed spans."""
+        ...
+
+class ThroughputCounter:
+    """Event rate over a sliding window of `window_secs` seconds.
+
+    With `lock_free=True`, events are counted in per-second atomic buckets,
+    which avoids lock contention at the cost of slight inaccuracy.
+    """
+
+    def __init__(self, window_secs: int = 60, lock_free: bool = False) -> None: ...
+    def record(self) -> None:
+        """Record one event now."""
+        ...
+    def get_rate(self) -> float:
+        """Get events per second over the current window."""
+        ...
+    def get_peak_rate(self) -> float:
+        """Get the highest rate observed since creation or the last reset."""
+        ...
+    def reset(self) -> None:
+        """Forget all events and the peak rate."""
+        ...
+    def is_lock_free(self) -> bool: ...
+
+class HotPathDetector:
+    """Counts calls per label to find the most frequently executed code paths."""
+
+    def __init__(self) -> None: ...
+    def record(self, label: str) -> None:
+        """Count one call for a label."""
+        ...
+    def get_hot_paths(self, top_n: int) -> list[tuple[str, int]]:
+        """Get the `top_n` labels by call count, highest first."""
+        ...
+    def get_threshold_paths(self, min_calls: int) -> list[tuple[str, int]]:
+        """Get labels called at least `min_calls` times, highest first."""
+        ...
+    def reset(self, label: str | None = None) -> None:
+        """Forget one label, or all labels."""
+        ...
+    def decay(self, factor: float) -> None:
+        """Multiply every count by `factor` (0.0 to 1.0)."""
+        ...
+
+class SamplingProfiler:
+    """Samples the Python stacks of all threads for flamegraph export."""
+
+    def __init__(self) -> None: ...
+    def start(self, interval_ms: int = 10, max_samples: int = 10000) -> None:
+        """Start sampling, keeping at most `max_samples` stacks."""
+        ...
+    def stop(self) -> int:
+        """Stop sampling and return the number of samples collected."""
+        ...
+    def is_running(self) -> bool: ...
+    def clear(self) -> None:
+        """Discard all collected samples."""
+        ...
+    def export_folded_stacks(self) -> str:
+        """Export samples in folded format (`a;b;c N` per line)."""
+        ...
+
+# Block for GraphQL helpers.
+class DataLoader:
+    """Batches and caches loads made in the same event loop iteration to avoid N+1 queries.
+
+    `batch_fn(keys)` may be sync or async and must return one value per key, in
+    key order. An exception instance in place of a value fails only that key.
+    """
+
+    def __init__(
+        self,
+        batch_fn: typing.Callable[[list[typing.Any]], typing.Any],
+        max_batch_size: int = 100,
+        cache: bool = True,
+    ) -> None: ...
+    def load(self, key: typing.Any) -> typing.Awaitable[typing.Any]:
+        """Load a value by key, batched with other loads in this iteration."""
+        ...
+    def load_many(self, keys: list[typing.Any]) -> typing.Awaitable[list[typing.Any]]:
+        """Load several keys and return their values in key order."""
+        ...
+    def clear(self, key: typing.Any) -> None:
+        """Forget the cached value for a key."""
+        ...
+    def clear_all(self) -> None:
+        """Forget all cached values."""
+        ...
+
+# Block for JSON helpers.
+def generate_json_schema(annotation: typing.Any) -> str:
+    """Generate a JSON Schema document (as a JSON string) for a type annotation."""
+    ...
+def encode_rows(
+    rows: list[dict[str, typing.Any]], schema_hint: list[str] | None = None
+) -> bytes:
+    """Encode a list of dicts sharing one key set as a JSON array.
+
+    Raises ValueError for circular references or very deeply nested values.
+    """
+    ...
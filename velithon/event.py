@@ -18,9 +18,28 @@ class EventChannel(RustEventChannel):
     It allows for registering listeners and emitting events across the application.
     """
 
-    def __init__(self, buffer_size: int = 1000):
-        """Initialize the EventChannel with an optional buffer size for event handling."""  # noqa: E501
+    def __init__(
+        self,
+        buffer_size: int = 1000,
+        backpressure_strategy: str = 'block',
+        record_history: bool = False,
+        max_history_size: int = 1000,
+    ):
+        """Initialize the EventChannel with an optional buffer size for event handling.
+
+        Args:
+            buffer_size: Maximum number of queued events per event name.
+            backpressure_strategy: What to do when a queue is full: 'block' waits,
+                'drop' discards the event, 'error' raises RuntimeError.
+            record_history: Keep emitted events so they can be replayed later.
+            max_history_size: Maximum number of recorded events; the oldest are
+                discarded first.
+
+        """
         self.buffer_size = buffer_size
+        self.backpressure_strategy = backpressure_strategy
+        self.record_history = record_history
+        self.max_history_size = max_history_size
         self.events: list[tuple[str, typing.Callable, bool]] = []
 
     def on_event(self, event_name: str):
@@ -6,6 +6,8 @@ logging middleware, and performance monitoring utilities.
 
 import inspect
 import logging
+from collections.abc import Callable
+from contextvars import ContextVar, Token
 
 from velithon._velithon import (
     configure_logger as rust_configure_logger,
@@ -45,6 +47,45 @@ from velithon._velithon import (
 )
 
 
+# Correlation ID of the current task. Context variables are copied per asyncio
+# task, so concurrent requests each see their own ID. A callable is resolved when
+# a record is logged, so an ID is only created once something is actually logged.
+_correlation_id: ContextVar[str | Callable[[], str] | None] = ContextVar(
+    'velithon_correlation_id', default=None
+)
+
+
+def set_correlation_id(correlation_id: str | Callable[[], str] | None) -> Token:
+    """Set the correlation ID added to records logged from the current context.
+
+    Returns a token for ``reset_correlation_id``.
+    """
+    return _correlation_id.set(correlation_id)
+
+
+def reset_correlation_id(token: Token) -> None:
+    """Restore the correlation ID that was set before ``set_correlation_id``."""
+    _correlation_id.reset(token)
+
+
+def get_correlation_id() -> str | None:
+    """Return the correlation ID of the current context, if any."""
+    correlation_id = _correlation_id.get()
+    if callable(correlation_id):
+        return correlation_id()
+    return correlation_id
+
+
+def _extra_for_rust(extra: dict | None) -> dict[str, str]:
+    """Convert extra fields to strings and add the current correlation ID."""
+    extra_str = {k: str(v) for k, v in extra.items()} if extra else {}
+    if 'correlation_id' not in extra_str:
+        correlation_id = get_correlation_id()
+        if correlation_id is not None:
+            extra_str['correlation_id'] = correlation_id
+    return extra_str
+
+
 class RustLoggingHandler(logging.Handler):
     """Python logging Handler that forwards log records to the Rust logging.
 
@@ -101,9 +142,7 @@ class RustLoggingHandler(logging.Handler):
                 }
 
             # Convert extra fields to string dict for Rust compatibility
-            extra_str_dict = (
-                {k: str(v) for k, v in extra_fields.items()} if extra_fields else {}
-            )
+            extra_str_dict = _extra_for_rust(extra_fields)
 
             # Map Python log levels to our Rust functions
             if record.levelno >= logging.CRITICAL:
@@ -193,7 +232,11 @@ class RustLogger:
         if args:
             msg = msg % args
         module, line = self._get_caller_info()
-        rust_log_debug(msg, module, line)
+        extra = _extra_for_rust(kwargs.get('extra'))
+        if extra:
+            rust_log_debug_with_extra(msg, module, line, extra)
+        else:
+            rust_log_debug(msg, module, line)
 
     def info(self, msg: str, *args, **kwargs) -> None:
         """Log an info message."""
@@ -202,11 +245,9 @@ class RustLogger:
         module, line = self._get_caller_info()
 
         # Handle extra fields for structured logging
-        extra = kwargs.get('extra', {})
+        extra = _extra_for_rust(kwargs.get('extra'))
         if extra:
-            # Convert all values to strings for Rust compatibility
-            extra_str = {k: str(v) for k, v in extra.items()}
-            rust_log_info_with_extra(msg, module, line, extra_str)
+            rust_log_info_with_extra(msg, module, line, extra)
         else:
             rust_log_info(msg, module, line)
 
@@ -215,7 +256,11 @@ class RustLogger:
         if args:
             msg = msg % args
         module, line = self._get_caller_info()
-        rust_log_warn(msg, module, line)
+        extra = _extra_for_rust(kwargs.get('extra'))
+        if extra:
+            rust_log_warn_with_extra(msg, module, line, extra)
+        else:
+            rust_log_warn(msg, module, line)
 
     def warn(self, msg: str, *args, **kwargs) -> None:
         """Alias for warning."""
@@ -226,14 +271,22 @@ class RustLogger:
         if args:
             msg = msg % args
         module, line = self._get_caller_info()
-        rust_log_error(msg, module, line)
+        extra = _extra_for_rust(kwargs.get('extra'))
+        if extra:
+            rust_log_error_with_extra(msg, module, line, extra)
+        else:
+            rust_log_error(msg, module, line)
 
     def critical(self, msg: str, *args, **kwargs) -> None:
         """Log a critical message."""
         if args:
             msg = msg % args
         module, line = self._get_caller_info()
-        rust_log_critical(msg, module, line)
+        extra = _extra_for_rust(kwargs.get('extra'))
+        if extra:
+            rust_log_critical_with_extra(msg, module, line, extra)
+        else:
+            rust_log_critical(msg, module, line)
 
     def isEnabledFor(self, level: int) -> bool:
         """Check if logging is enabled for the given level."""
@@ -316,5 +369,8 @@ def get_logger(name: str = 'velithon') -> RustLogger:
 __all__ = [
     'RustLogger',
     'configure_logger',
+    'get_correlation_id',
     'get_logger',
+    'reset_correlation_id',
+    'set_correlation_id',
 ]
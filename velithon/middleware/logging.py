@@ -9,7 +9,7 @@ import traceback
 
 from velithon.datastructures import Protocol, Scope
 from velithon.exceptions import HTTPException
-from velithon.logging import get_logger
+from velithon.logging import get_logger, reset_correlation_id, set_correlation_id
 from velithon.middleware.base import BaseHTTPMiddleware
 from velithon.responses import JSONResponse
 
@@ -34,6 +34,15 @@ class LoggingMiddleware(BaseHTTPMiddleware):
         self._logger = get_logger(__name__)
 
     async def process_http_request(self, scope: Scope, protocol: Protocol) -> None:
+        """Process HTTP request with its request ID as the log correlation ID."""
+        # Resolved when something is logged, so the ID is not generated up front
+        token = set_correlation_id(lambda: scope.request_id)
+        try:
+            await self._log_http_request(scope, protocol)
+        finally:
+            reset_correlation_id(token)
+
+    async def _log_http_request(self, scope: Scope, protocol: Protocol) -> None:
         """Process HTTP request and log details including performance metrics."""
         # Check if logging is enabled at INFO level first to avoid timing calculations
         # if we're not going to log anything